@@ -35,15 +35,15 @@ pub enum Message {
 
 #[derive(Debug)]
 struct BoardCanvas {
-    game: GameManager,
+    board: Board,
     selected_piece: Option<Position>,
     available_moves: Vec<Move>,
 }
 
 impl BoardCanvas {
-    fn new(game: &GameManager, selected: Option<Position>, available: &[Move]) -> Self {
+    fn new(board: &Board, selected: Option<Position>, available: &[Move]) -> Self {
         Self {
-            game: game.clone(),
+            board: board.clone(),
             selected_piece: selected,
             available_moves: available.to_vec(),
         }
@@ -61,11 +61,12 @@ impl canvas::Program<Message> for BoardCanvas {
         cursor: mouse::Cursor,
     ) -> (event::Status, Option<Message>) {
         if let canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
-            let cell_size = bounds.width / 8.0;
+            let size = self.board.size();
+            let cell_size = bounds.width / size as f32;
             let rel_pos = cursor.position().unwrap_or(Point::new(0.0, 0.0)) - bounds.position();
             let col = (rel_pos.x / cell_size) as usize;
             let row = (rel_pos.y / cell_size) as usize;
-            if row < 8 && col < 8 {
+            if row < size && col < size {
                 return (
                     event::Status::Captured,
                     Some(Message::CellClicked(row, col)),
@@ -84,16 +85,17 @@ impl canvas::Program<Message> for BoardCanvas {
         _cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
         let mut frame = Frame::new(renderer, bounds.size());
-        let cell_size = bounds.width / 8.0;
+        let size = self.board.size();
+        let cell_size = bounds.width / size as f32;
 
-        for row in 0..8 {
-            for col in 0..8 {
+        for row in 0..size {
+            for col in 0..size {
                 let x = col as f32 * cell_size;
                 let y = row as f32 * cell_size;
-                let cell_color = if (row + col) % 2 == 0 {
-                    Color::from_rgb(0.93, 0.93, 0.82)
-                } else {
+                let cell_color = if self.board.rules().is_playable(&Position { row, col }) {
                     Color::from_rgb(0.46, 0.59, 0.34)
+                } else {
+                    Color::from_rgb(0.93, 0.93, 0.82)
                 };
                 frame.fill_rectangle(
                     Point::new(x, y),
@@ -101,13 +103,12 @@ impl canvas::Program<Message> for BoardCanvas {
                     cell_color,
                 );
 
-                if let Some(piece) = self.game.board.get_square(&Position { row, col }) {
+                if let Some(piece) = self.board.get_square(&Position { row, col }) {
                     let center = Point::new(x + cell_size / 2.0, y + cell_size / 2.0);
                     let radius = cell_size / 2.0 * 0.8;
-                    let bg_color = if piece.owner == Side::Player {
-                        Color::from_rgb(1.0, 0.0, 0.0)
-                    } else {
-                        Color::from_rgb(0.0, 0.0, 1.0)
+                    let (bg_color, mark_color) = match piece.owner {
+                        Side::Dark => (Color::from_rgb(0.15, 0.15, 0.15), Color::WHITE),
+                        Side::Light => (Color::from_rgb(0.96, 0.94, 0.86), Color::BLACK),
                     };
                     frame.fill(&Path::circle(center, radius), bg_color);
 
@@ -115,7 +116,7 @@ impl canvas::Program<Message> for BoardCanvas {
                         let text = Text {
                             content: "K".to_string(),
                             position: center,
-                            color: Color::WHITE,
+                            color: mark_color,
                             size: iced::Pixels(20.0),
                             horizontal_alignment: iced::alignment::Horizontal::Center,
                             vertical_alignment: iced::alignment::Vertical::Center,
@@ -159,8 +160,8 @@ impl CheckersUI {
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::CellClicked(row, col) => {
-                // Don't allow clicking if it's AI's turn
-                if self.game.current_turn == Side::AI {
+                // Don't allow clicking while the computer is to move
+                if self.game.is_computer_turn() {
                     return Task::none();
                 }
 
@@ -171,58 +172,78 @@ impl CheckersUI {
                         self.selected_piece = None;
                         self.available_moves.clear();
 
-                        // After player move, trigger AI move if it's AI's turn with delay
-                        if self.game.current_turn == Side::AI && !self.game.game_over {
-                            return Task::perform(
-                                async {
-                                    async_std::task::sleep(std::time::Duration::from_millis(800))
-                                        .await;
-                                },
-                                |_| Message::AiMove,
-                            );
+                        // The capturing piece stays selected until its chain is complete
+                        if let Some(pending) = &self.game.pending_capture {
+                            self.select(pending.position);
                         }
+                        return self.schedule_ai_move();
                     } else {
                         self.selected_piece = None;
                         self.available_moves.clear();
                     }
-                } else {
-                    if let Some(piece) = self.game.board.get_square(&pos) {
-                        if piece.owner == self.game.current_turn {
-                            self.selected_piece = Some(pos);
-                            self.available_moves = self.game.get_possible_moves(pos);
-                        }
-                    }
+                } else if let Some(piece) = self.game.board.get_square(&pos)
+                    && piece.owner == self.game.current_turn
+                {
+                    self.select(pos);
                 }
                 Task::none()
             }
             Message::AiMove => {
-                if self.game.current_turn == Side::AI && !self.game.game_over {
+                if self.game.is_computer_turn() && !self.game.is_over() {
                     let _ = self.game.make_ai_move();
-
-                    // If still AI's turn (multiple jumps), schedule another AI move with shorter delay
-                    if self.game.current_turn == Side::AI && !self.game.game_over {
-                        return Task::perform(
-                            async {
-                                async_std::task::sleep(std::time::Duration::from_millis(500)).await;
-                            },
-                            |_| Message::AiMove,
-                        );
-                    }
                 }
-                Task::none()
+                self.schedule_ai_move()
+            }
+        }
+    }
+
+    // Selects the piece on `pos` and lists where it can go. Captures are
+    // entered one jump at a time: two chains may end on the same square
+    // after taking different pieces, but each jump takes only the one piece
+    // between its ends.
+    fn select(&mut self, pos: Position) {
+        self.selected_piece = Some(pos);
+        self.available_moves.clear();
+        for mv in self.game.get_possible_moves(pos) {
+            let mv = match &mv.move_type {
+                MoveType::Capture(sequence) => Move {
+                    from: mv.from,
+                    to: sequence.path()[0],
+                    move_type: MoveType::Jump,
+                },
+                _ => mv,
+            };
+            if !self.available_moves.contains(&mv) {
+                self.available_moves.push(mv);
             }
         }
     }
 
+    // Gives the computer its move after a short delay if it is to move next
+    fn schedule_ai_move(&self) -> Task<Message> {
+        if !self.game.is_computer_turn() || self.game.is_over() {
+            return Task::none();
+        }
+        Task::perform(
+            async {
+                async_std::task::sleep(std::time::Duration::from_millis(800)).await;
+            },
+            |_| Message::AiMove,
+        )
+    }
+
     fn view(&self) -> Element<'_, Message> {
         let board = self.view_board();
-        let status = if self.game.game_over {
-            text(format!(
-                "Game Over! Winner: {:?}",
-                self.game.winner.unwrap()
-            ))
-        } else {
-            text(format!("Current turn: {:?}", self.game.current_turn))
+        let status = match self.game.result {
+            Some(GameResult {
+                winner: Some(winner),
+                reason,
+            }) => text(format!("Game Over! Winner: {:?} ({:?})", winner, reason)),
+            Some(GameResult {
+                winner: None,
+                reason,
+            }) => text(format!("Game Over! Draw ({:?})", reason)),
+            None => text(format!("Current turn: {:?}", self.game.current_turn)),
         };
         let content = column![board, status].spacing(20).padding(20);
 
@@ -238,7 +259,7 @@ impl CheckersUI {
 impl CheckersUI {
     fn view_board(&self) -> Element<'_, Message> {
         Canvas::new(BoardCanvas::new(
-            &self.game,
+            &self.game.board,
             self.selected_piece,
             &self.available_moves,
         ))
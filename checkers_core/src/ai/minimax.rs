@@ -1,144 +1,249 @@
+use super::score::Score;
+use super::transposition::{Bound, Entry, TranspositionTable};
 use crate::board::Board;
 use crate::board::movement::Move;
 use crate::board::piece::side::Side;
-use crate::move_controller::moves_per_side;
+use crate::move_controller::{MoveList, generate_moves, moves_per_side};
+use crate::rules::RuleSet;
 
-const MAX_DEPTH: usize = 6;
+pub(crate) const MAX_DEPTH: usize = 6;
 
-pub fn decide_move(board: &Board, side: Side, depth: usize) -> Option<Move> {
-    let moves = moves_per_side(board, side);
-    if moves.is_empty() {
-        return None;
-    }
+pub fn decide_move<R: RuleSet>(board: &Board<R>, side: Side, depth: usize) -> Option<Move> {
+    analyse(board, side, depth).map(|(mv, _)| mv)
+}
+
+/// The best move for `side` together with its score, searching `depth`
+/// plies ahead.
+pub fn analyse<R: RuleSet>(board: &Board<R>, side: Side, depth: usize) -> Option<(Move, Score)> {
+    analyse_with(board, side, depth, &mut TranspositionTable::default())
+}
+
+/// Like `analyse`, but keeps what it finds in `table` and starts from what
+/// earlier searches left there.
+pub fn analyse_with<R: RuleSet>(
+    board: &Board<R>,
+    side: Side,
+    depth: usize,
+    table: &mut TranspositionTable,
+) -> Option<(Move, Score)> {
+    best_of(board, side, moves_per_side(board, side), depth, table)
+}
 
-    let mut best_move = None;
-    let mut best_score = i16::MIN;
+// Picks the move out of `moves` that searches best for `side`. Capture chains
+// are whole moves, so the opponent replies only once a chain is complete.
+pub(crate) fn best_of<R: RuleSet>(
+    board: &Board<R>,
+    side: Side,
+    moves: Vec<Move>,
+    depth: usize,
+    table: &mut TranspositionTable,
+) -> Option<(Move, Score)> {
+    let mut best: Option<(Move, Score)> = None;
+    let mut alpha = -Score::INFINITY;
+    // The search plays moves on this copy and takes them back as it goes
+    let mut board = board.clone();
+    let mut search = Search {
+        lists: vec![],
+        table,
+    };
 
     for mv in moves {
-        let mut board_clone = board.clone();
-        if board_clone.apply_move(&mv).is_ok() {
-            let score = minimax(&board_clone, depth - 1, i16::MIN, i16::MAX, false, side);
-            if score > best_score {
-                best_score = score;
-                best_move = Some(mv);
-            }
+        let Ok(undo) = board.apply_move(&mv) else {
+            continue;
+        };
+        let score = -negamax(
+            &mut board,
+            depth.saturating_sub(1),
+            1,
+            -Score::INFINITY,
+            -alpha,
+            side.opposite(),
+            &mut search,
+        );
+        board.unmake_move(&mv, undo);
+
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_score)| score > *best_score)
+        {
+            best = Some((mv, score));
         }
+        alpha = alpha.max(score);
     }
 
-    best_move
+    best
 }
 
-fn minimax(
-    board: &Board,
+// What the search carries from one node to the next: a move list per ply,
+// reused as the search goes, and the positions it has already been through.
+struct Search<'a> {
+    lists: Vec<MoveList>,
+    table: &'a mut TranspositionTable,
+}
+
+// Scores `board` for `side`, the side to move, searching `depth` plies ahead.
+// A reply's score is the negation of the score it gets for the opponent, so
+// both sides are searched the same way. `ply` is the distance from the root,
+// which won and lost scores count from.
+fn negamax<R: RuleSet>(
+    board: &mut Board<R>,
     depth: usize,
-    mut alpha: i16,
-    mut beta: i16,
-    is_maximizing: bool,
-    ai_side: Side,
-) -> i16 {
-    if depth == 0 {
-        return evaluate_board(board, ai_side);
+    ply: usize,
+    mut alpha: Score,
+    beta: Score,
+    side: Side,
+    search: &mut Search,
+) -> Score {
+    let key = board.zobrist(side);
+    let mut hint = None;
+    if depth > 0
+        && let Some(entry) = search.table.probe(key)
+    {
+        let score = entry.score.counted_from_root(ply);
+        if entry.depth >= depth {
+            let settled = match entry.bound {
+                Bound::Exact => true,
+                Bound::Lower => score >= beta,
+                Bound::Upper => score <= alpha,
+            };
+            if settled {
+                return score;
+            }
+        }
+        hint = entry.best_move.clone();
     }
 
-    let current_side = if is_maximizing {
-        Side::AI
-    } else {
-        Side::Player
-    };
-    let moves = moves_per_side(board, current_side);
+    if search.lists.len() <= ply + 1 {
+        search.lists.resize_with(ply + 2, MoveList::new);
+    }
+    let mut moves = std::mem::take(&mut search.lists[ply]);
+    generate_moves(board, side, &mut moves);
 
-    if moves.is_empty() {
+    let score = if moves.is_empty() {
         // No moves available - the game is over
-        if is_maximizing {
-            return i16::MIN + 1; // AI loses
+        if board.rules().losing_game() {
+            Score::win_in(ply) // Side to move wins
         } else {
-            return i16::MAX - 1; // AI wins
+            Score::loss_in(ply) // Side to move loses
         }
-    }
-
-    if is_maximizing {
-        let mut max_eval = i16::MIN;
-        for mv in moves {
-            let mut board_clone = board.clone();
-            if board_clone.apply_move(&mv).is_ok() {
-                let eval = minimax(&board_clone, depth - 1, alpha, beta, false, ai_side);
-                max_eval = max_eval.max(eval);
-                alpha = alpha.max(eval);
-                if beta <= alpha {
-                    break; // Beta cutoff
-                }
-            }
-        }
-        max_eval
+    } else if depth == 0 {
+        evaluate_board(board, side, ply, moves.len(), &mut search.lists[ply + 1])
     } else {
-        let mut min_eval = i16::MAX;
-        for mv in moves {
-            let mut board_clone = board.clone();
-            if board_clone.apply_move(&mv).is_ok() {
-                let eval = minimax(&board_clone, depth - 1, alpha, beta, true, ai_side);
-                min_eval = min_eval.min(eval);
-                beta = beta.min(eval);
-                if beta <= alpha {
-                    break; // Alpha cutoff
-                }
+        let alpha_start = alpha;
+        let mut best = -Score::INFINITY;
+        let mut best_index = None;
+        // The move that was best here before is the likeliest to cut the
+        // search off, so it goes first
+        let first = hint.and_then(|hint| moves.iter().position(|mv| *mv == hint));
+        let rest = (0..moves.len()).filter(|index| Some(*index) != first);
+        for index in first.into_iter().chain(rest) {
+            let mv = &moves[index];
+            let Ok(undo) = board.apply_move(mv) else {
+                continue;
+            };
+            let eval = -negamax(
+                board,
+                depth - 1,
+                ply + 1,
+                -beta,
+                -alpha,
+                side.opposite(),
+                search,
+            );
+            board.unmake_move(mv, undo);
+
+            if eval > best {
+                best = eval;
+                best_index = Some(index);
+            }
+            alpha = alpha.max(eval);
+            if alpha >= beta {
+                break; // Cutoff
             }
         }
-        min_eval
-    }
-}
 
-fn evaluate_board(board: &Board, ai_side: Side) -> i16 {
-    let ai_pieces = board.all_pieces_of_side(ai_side);
-    let opponent_pieces = board.all_pieces_of_side(ai_side.opposite());
+        if let Some(index) = best_index {
+            let bound = if best <= alpha_start {
+                Bound::Upper
+            } else if best >= beta {
+                Bound::Lower
+            } else {
+                Bound::Exact
+            };
+            search.table.store(Entry {
+                key,
+                depth,
+                score: best.counted_from_position(ply),
+                bound,
+                best_move: Some(moves[index].clone()),
+            });
+        }
+        best
+    };
 
-    if opponent_pieces.is_empty() {
-        return i16::MAX - 1; // AI wins
-    }
-    if ai_pieces.is_empty() {
-        return i16::MIN + 1; // AI loses
+    search.lists[ply] = moves;
+    score
+}
+
+// Scores `board` from the point of view of `side`, which is to move and has
+// `own_moves` moves to choose from. `scratch` is spare room to list the
+// opponent's moves in.
+fn evaluate_board<R: RuleSet>(
+    board: &Board<R>,
+    side: Side,
+    ply: usize,
+    own_moves: usize,
+    scratch: &mut MoveList,
+) -> Score {
+    // Whatever `side` plays next leaves the opponent without a move
+    if board.pieces_of_side(side.opposite()).next().is_none() {
+        return if board.rules().losing_game() {
+            Score::loss_in(ply + 1)
+        } else {
+            Score::win_in(ply + 1)
+        };
     }
 
-    let mut score: i16 = 0;
+    // In a losing game the side that runs out of pieces wins
+    let sign = if board.rules().losing_game() { -1 } else { 1 };
+
+    let rules = board.rules();
+    let last_row = board.size() - 1;
+    let center = 2..board.size() - 2;
+    let mut score: i32 = 0;
 
     // Piece count evaluation
-    for piece in &ai_pieces {
+    for piece in board.pieces_of_side(side) {
         score += if piece.data.is_king { 30 } else { 10 };
 
         // Position bonus: pieces closer to opponent's side are more valuable
-        let row_bonus = match ai_side {
-            Side::AI => piece.position.row as i16,
-            Side::Player => (7 - piece.position.row) as i16,
-        };
+        let row_bonus = (last_row - rules.promotion_row(side).abs_diff(piece.position.row)) as i32;
         score += row_bonus;
+
+        // Bonus for controlling center squares
+        if center.contains(&piece.position.row) && center.contains(&piece.position.col) {
+            score += 2;
+        }
     }
 
-    for piece in &opponent_pieces {
+    for piece in board.pieces_of_side(side.opposite()) {
         score -= if piece.data.is_king { 30 } else { 10 };
 
-        let row_bonus = match ai_side.opposite() {
-            Side::AI => piece.position.row as i16,
-            Side::Player => (7 - piece.position.row) as i16,
-        };
+        let row_bonus = (last_row
+            - rules
+                .promotion_row(side.opposite())
+                .abs_diff(piece.position.row)) as i32;
         score -= row_bonus;
     }
 
     // Mobility evaluation: more moves available is better
-    let ai_moves = moves_per_side(board, ai_side);
-    let opponent_moves = moves_per_side(board, ai_side.opposite());
-
-    score += ai_moves.len() as i16;
-    score -= opponent_moves.len() as i16;
+    generate_moves(board, side.opposite(), scratch);
+    score += own_moves as i32;
+    score -= scratch.len() as i32;
 
-    // Bonus for controlling center squares
-    for piece in &ai_pieces {
-        if (2..6).contains(&piece.position.row) && (2..6).contains(&piece.position.col) {
-            score += 2;
-        }
-    }
-
-    score
+    Score::heuristic(sign * score)
 }
 
-pub fn get_best_move(board: &Board, side: Side) -> Option<Move> {
+pub fn get_best_move<R: RuleSet>(board: &Board<R>, side: Side) -> Option<Move> {
     decide_move(board, side, MAX_DEPTH)
 }
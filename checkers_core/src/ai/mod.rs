@@ -1,3 +1,7 @@
 pub mod minimax;
+pub mod score;
+pub mod transposition;
 
-pub use minimax::{decide_move, get_best_move};
+pub use minimax::{analyse, analyse_with, decide_move, get_best_move};
+pub use score::Score;
+pub use transposition::{Bound, Entry, TranspositionTable};
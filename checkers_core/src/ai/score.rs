@@ -0,0 +1,93 @@
+use std::ops::Neg;
+
+/// How good a position is for the side to move. Won and lost positions rank
+/// above and below every heuristic value and carry the number of plies to the
+/// end of the game, so a quicker win and a slower loss score higher.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Score(i32);
+
+impl Score {
+    const WIN: i32 = 1_000_000;
+    // Longest distance to the end a win or loss keeps track of
+    const MAX_PLIES: i32 = 10_000;
+    const HEURISTIC_LIMIT: i32 = Self::WIN - Self::MAX_PLIES - 1;
+
+    /// Above every score a position can get, for the bounds of a search.
+    pub const INFINITY: Score = Score(Self::WIN + 1);
+    pub const DRAW: Score = Score(0);
+
+    /// An estimate of a position that is not decided yet. Values beyond the
+    /// range kept apart for wins and losses are clamped to it.
+    pub fn heuristic(value: i32) -> Score {
+        Score(value.clamp(-Self::HEURISTIC_LIMIT, Self::HEURISTIC_LIMIT))
+    }
+
+    pub fn win_in(plies: usize) -> Score {
+        Score(Self::WIN - Self::clamp_plies(plies))
+    }
+
+    pub fn loss_in(plies: usize) -> Score {
+        -Score::win_in(plies)
+    }
+
+    fn clamp_plies(plies: usize) -> i32 {
+        plies.min(Self::MAX_PLIES as usize) as i32
+    }
+
+    pub fn is_win(&self) -> bool {
+        self.0 > Self::HEURISTIC_LIMIT
+    }
+
+    pub fn is_loss(&self) -> bool {
+        self.0 < -Self::HEURISTIC_LIMIT
+    }
+
+    /// Plies until the side to move wins, if the position is won.
+    pub fn plies_to_win(&self) -> Option<usize> {
+        self.is_win().then(|| (Self::WIN - self.0) as usize)
+    }
+
+    /// Plies until the side to move loses, if the position is lost.
+    pub fn plies_to_loss(&self) -> Option<usize> {
+        self.is_loss().then(|| (Self::WIN + self.0) as usize)
+    }
+
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+
+    // A score found `ply` plies from the root, with a win or loss counted
+    // from the position itself rather than from the root, as it is stored
+    // in the transposition table
+    pub(crate) fn counted_from_position(self, ply: usize) -> Score {
+        let ply = Self::clamp_plies(ply);
+        if self.is_win() {
+            Score(self.0 + ply)
+        } else if self.is_loss() {
+            Score(self.0 - ply)
+        } else {
+            self
+        }
+    }
+
+    // The reverse of `counted_from_position`, for a stored score met `ply`
+    // plies from the root
+    pub(crate) fn counted_from_root(self, ply: usize) -> Score {
+        let ply = Self::clamp_plies(ply);
+        if self.is_win() {
+            Score(self.0 - ply)
+        } else if self.is_loss() {
+            Score(self.0 + ply)
+        } else {
+            self
+        }
+    }
+}
+
+impl Neg for Score {
+    type Output = Score;
+
+    fn neg(self) -> Score {
+        Score(-self.0)
+    }
+}
@@ -0,0 +1,104 @@
+use super::score::Score;
+use crate::board::movement::Move;
+
+/// Entries a table holds unless told otherwise.
+pub const DEFAULT_ENTRIES: usize = 1 << 14;
+
+/// How a stored score relates to the true score of the position.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Bound {
+    /// The search saw every move; the score is the true one.
+    Exact,
+    /// A move was good enough to cut the search off; the true score is at
+    /// least this high.
+    Lower,
+    /// No move reached the search window; the true score is at most this
+    /// high.
+    Upper,
+}
+
+/// What the search learned about one position.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    /// Zobrist key of the position, side to move included.
+    pub key: u64,
+    /// Plies the position was searched below it.
+    pub depth: usize,
+    pub score: Score,
+    pub bound: Bound,
+    pub best_move: Option<Move>,
+}
+
+/// Positions the search has already been through, so that one reached again
+/// by another move order is not searched twice. The table has a fixed number
+/// of slots, picked by key; when two positions share a slot the one searched
+/// deeper stays.
+///
+/// Keys cover the pieces, the side to move and the rules' `State`, but not
+/// the rule set itself, so a table should be used with one rule set.
+#[derive(Clone, Debug)]
+pub struct TranspositionTable {
+    entries: Vec<Option<Entry>>,
+}
+
+impl TranspositionTable {
+    /// A table with room for `entries` positions, at least one.
+    pub fn new(entries: usize) -> Self {
+        TranspositionTable {
+            entries: vec![None; entries.max(1)],
+        }
+    }
+
+    /// Number of slots in the table.
+    pub fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Number of slots in use.
+    pub fn len(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.is_some()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(Option::is_none)
+    }
+
+    /// Forgets every stored position.
+    pub fn clear(&mut self) {
+        self.entries.fill(None);
+    }
+
+    /// Drops the stored positions and makes room for `entries` of them.
+    pub fn resize(&mut self, entries: usize) {
+        *self = Self::new(entries);
+    }
+
+    /// The entry stored for the position with `key`, if it is still there.
+    pub fn probe(&self, key: u64) -> Option<&Entry> {
+        self.entries[self.slot(key)]
+            .as_ref()
+            .filter(|entry| entry.key == key)
+    }
+
+    /// Stores `entry`, unless its slot holds another position searched
+    /// deeper.
+    pub fn store(&mut self, entry: Entry) {
+        let slot = self.slot(entry.key);
+        let replace = self.entries[slot]
+            .as_ref()
+            .is_none_or(|old| old.key == entry.key || old.depth <= entry.depth);
+        if replace {
+            self.entries[slot] = Some(entry);
+        }
+    }
+
+    fn slot(&self, key: u64) -> usize {
+        (key % self.entries.len() as u64) as usize
+    }
+}
+
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        Self::new(DEFAULT_ENTRIES)
+    }
+}
@@ -0,0 +1,119 @@
+use super::movement::direction::{MoveDirection, MoveHorizontal, MoveVertical};
+use super::piece::PieceData;
+use super::piece::side::Side;
+use super::position::Position;
+
+// Dark squares on rows 0, 2, 4 and 6, and on rows 1, 3, 5 and 7
+const EVEN_ROWS: u32 = 0x0F0F_0F0F;
+const ODD_ROWS: u32 = 0xF0F0_F0F0;
+// First and last dark square of every row
+const FIRST_OF_ROW: u32 = 0x1111_1111;
+const LAST_OF_ROW: u32 = 0x8888_8888;
+
+/// An 8x8 board kept as bit masks over its 32 dark squares, those where
+/// `row + col` is odd. Bit `4 * row + col / 2` stands for (`row`, `col`), so
+/// the squares are numbered row by row from the top left.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct BitBoard {
+    pub dark: u32,
+    pub light: u32,
+    pub kings: u32,
+}
+
+impl BitBoard {
+    /// The mask for `pos`, or `None` if it is not a dark square of the board.
+    pub fn bit(pos: &Position) -> Option<u32> {
+        if pos.row >= 8 || pos.col >= 8 || (pos.row + pos.col).is_multiple_of(2) {
+            return None;
+        }
+        Some(1 << (pos.row * 4 + pos.col / 2))
+    }
+
+    /// The square of the lowest bit set in `mask`.
+    pub fn position(mask: u32) -> Position {
+        let index = mask.trailing_zeros() as usize;
+        let row = index / 4;
+        let col = index % 4 * 2 + if row.is_multiple_of(2) { 1 } else { 0 };
+        Position { row, col }
+    }
+
+    /// Every square set in `mask`, lowest bit first.
+    pub fn squares(mut mask: u32) -> impl Iterator<Item = u32> {
+        std::iter::from_fn(move || {
+            if mask == 0 {
+                return None;
+            }
+            let lowest = mask & mask.wrapping_neg();
+            mask ^= lowest;
+            Some(lowest)
+        })
+    }
+
+    /// Moves every square in `mask` one step along a diagonal `direction`.
+    /// Squares that would leave the board drop out. `None` if `direction` is
+    /// not a diagonal.
+    pub fn shift(mask: u32, direction: &MoveDirection) -> Option<u32> {
+        let shifted = match (direction.ver, direction.hor) {
+            (MoveVertical::Up, MoveHorizontal::Left) => {
+                ((mask & EVEN_ROWS) >> 4) | ((mask & ODD_ROWS & !FIRST_OF_ROW) >> 5)
+            }
+            (MoveVertical::Up, MoveHorizontal::Right) => {
+                ((mask & EVEN_ROWS & !LAST_OF_ROW) >> 3) | ((mask & ODD_ROWS) >> 4)
+            }
+            (MoveVertical::Down, MoveHorizontal::Left) => {
+                ((mask & EVEN_ROWS) << 4) | ((mask & ODD_ROWS & !FIRST_OF_ROW) << 3)
+            }
+            (MoveVertical::Down, MoveHorizontal::Right) => {
+                ((mask & EVEN_ROWS & !LAST_OF_ROW) << 5) | ((mask & ODD_ROWS) << 4)
+            }
+            _ => return None,
+        };
+        Some(shifted)
+    }
+
+    pub fn get(&self, pos: &Position) -> Option<PieceData> {
+        let bit = Self::bit(pos)?;
+        let owner = if self.dark & bit != 0 {
+            Side::Dark
+        } else if self.light & bit != 0 {
+            Side::Light
+        } else {
+            return None;
+        };
+        Some(PieceData {
+            owner,
+            is_king: self.kings & bit != 0,
+        })
+    }
+
+    pub fn set(&mut self, pos: &Position, piece: Option<PieceData>) {
+        let Some(bit) = Self::bit(pos) else {
+            debug_assert!(piece.is_none(), "{pos:?} is not a dark square");
+            return;
+        };
+
+        self.dark &= !bit;
+        self.light &= !bit;
+        self.kings &= !bit;
+        if let Some(piece) = piece {
+            match piece.owner {
+                Side::Dark => self.dark |= bit,
+                Side::Light => self.light |= bit,
+            }
+            if piece.is_king {
+                self.kings |= bit;
+            }
+        }
+    }
+
+    pub fn pieces(&self, side: Side) -> u32 {
+        match side {
+            Side::Dark => self.dark,
+            Side::Light => self.light,
+        }
+    }
+
+    pub fn empty(&self) -> u32 {
+        !(self.dark | self.light)
+    }
+}
@@ -1,14 +1,20 @@
+pub mod bitboard;
 pub mod movement;
 pub mod piece;
 pub mod position;
+mod zobrist;
+
+use std::hash::{Hash, Hasher};
 
 use crate::MoveError;
 use crate::board::piece::PieceInstance;
+use crate::rules::{EnglishDraughts, RuleSet};
 
+use self::bitboard::BitBoard;
 use self::movement::{Move, MoveType};
 use self::piece::PieceData;
 use self::piece::side::Side;
-use self::position::Position;
+use self::position::{Position, PositionError};
 
 #[derive(Debug)]
 pub enum BoardError {
@@ -16,35 +22,113 @@ pub enum BoardError {
     EmptySquare,
 }
 
+#[derive(Clone, PartialEq, Debug)]
+pub struct Board<R: RuleSet = EnglishDraughts> {
+    squares: Squares,
+    rules: R,
+    state: R::State,
+    // Zobrist hash of the pieces, kept up to date by `set_square`
+    hash: u64,
+}
+
+// Boards hash by their Zobrist key, which equal boards always share
+impl<R: RuleSet> Hash for Board<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+impl<R: RuleSet + Eq> Eq for Board<R> where R::State: Eq {}
+
+// 8x8 boards played on the dark squares fit in a `BitBoard`; every other
+// board keeps one entry per square, row by row.
+#[derive(Clone, PartialEq, Debug)]
+enum Squares {
+    Bits(BitBoard),
+    Grid(Vec<Option<PieceData>>),
+}
+
+/// What a move changed on a board, returned by `Board::apply_move` so that
+/// `Board::unmake_move` can take the move back.
+#[derive(Clone, Debug)]
+pub struct MoveUndo<R: RuleSet = EnglishDraughts> {
+    squares: Restore,
+    state: R::State,
+    hash: u64,
+}
+
 #[derive(Clone, Debug)]
-pub struct Board {
-    squares: [[Option<PieceData>; 8]; 8],
+enum Restore {
+    // The masks are small enough to keep whole
+    Bits(BitBoard),
+    Grid {
+        piece: PieceData,
+        taken: Vec<(Position, PieceData)>,
+    },
+}
+
+const PIECES: [PieceData; 4] = [
+    PieceData {
+        owner: Side::Dark,
+        is_king: false,
+    },
+    PieceData {
+        owner: Side::Dark,
+        is_king: true,
+    },
+    PieceData {
+        owner: Side::Light,
+        is_king: false,
+    },
+    PieceData {
+        owner: Side::Light,
+        is_king: true,
+    },
+];
+
+// A long-lived copy of `piece`, for squares that only exist as bits
+fn piece_ref(piece: PieceData) -> &'static PieceData {
+    let side = match piece.owner {
+        Side::Dark => 0,
+        Side::Light => 2,
+    };
+    &PIECES[side + piece.is_king as usize]
 }
 
 impl Board {
     pub fn setup() -> Self {
-        let mut board = Board {
-            squares: [[None; 8]; 8],
-        };
+        Self::setup_with(EnglishDraughts)
+    }
 
-        for row in 0..3 {
-            for col in 0..8 {
-                if (row + col) % 2 == 1 {
-                    board.squares[row][col] = Some(PieceData {
-                        owner: Side::AI,
-                        is_king: false,
-                    });
-                }
-            }
-        }
+    pub fn empty() -> Self {
+        Self::empty_with(EnglishDraughts)
+    }
+}
+
+impl<R: RuleSet> Board<R> {
+    pub fn setup_with(rules: R) -> Self {
+        let mut board = Self::empty_with(rules);
+        let size = board.size();
 
-        for row in 5..8 {
-            for col in 0..8 {
-                if (row + col) % 2 == 1 {
-                    board.squares[row][col] = Some(PieceData {
-                        owner: Side::Player,
-                        is_king: false,
-                    });
+        for row in 0..size {
+            let owner = if rules.home_rows(Side::Light).contains(&row) {
+                Side::Light
+            } else if rules.home_rows(Side::Dark).contains(&row) {
+                Side::Dark
+            } else {
+                continue;
+            };
+
+            for col in 0..size {
+                let pos = Position { row, col };
+                if rules.is_playable(&pos) {
+                    board.set_square(
+                        &pos,
+                        Some(PieceData {
+                            owner,
+                            is_king: false,
+                        }),
+                    );
                 }
             }
         }
@@ -52,50 +136,246 @@ impl Board {
         board
     }
 
+    pub fn empty_with(rules: R) -> Self {
+        let size = rules.board_size();
+        debug_assert!(
+            size.is_multiple_of(2) && size > 2 * rules.starting_rows(),
+            "board must have an even size with room between the two armies"
+        );
+        let fits_bitboard = size == 8
+            && (0..size * size).all(|index| {
+                let pos = Position {
+                    row: index / size,
+                    col: index % size,
+                };
+                rules.is_playable(&pos) == BitBoard::bit(&pos).is_some()
+            });
+        let squares = if fits_bitboard {
+            Squares::Bits(BitBoard::default())
+        } else {
+            Squares::Grid(vec![None; size * size])
+        };
+        Board {
+            squares,
+            rules,
+            state: R::State::default(),
+            hash: 0,
+        }
+    }
+
+    pub fn rules(&self) -> &R {
+        &self.rules
+    }
+
+    pub fn state(&self) -> &R::State {
+        &self.state
+    }
+
+    pub fn size(&self) -> usize {
+        self.rules.board_size()
+    }
+
+    /// Checks that (`row`, `col`) is a square pieces can stand on.
+    pub fn position(&self, row: isize, col: isize) -> Result<Position, PositionError> {
+        let pos = Position::setup(row, col, self.size())?;
+        if !self.rules.is_playable(&pos) {
+            return Err(PositionError::NotBlack);
+        }
+        Ok(pos)
+    }
+
+    /// A 64-bit Zobrist hash of the pieces on the board, the variant state
+    /// and `side_to_move`. The pieces' part changes piece by piece as moves
+    /// are played, rather than being worked out from scratch.
+    pub fn zobrist(&self, side_to_move: Side) -> u64 {
+        let hash = self.hash ^ zobrist::state_key(&self.state);
+        match side_to_move {
+            Side::Dark => hash,
+            Side::Light => hash ^ zobrist::LIGHT_TO_MOVE,
+        }
+    }
+
+    /// The bit masks behind the board, if it is small enough to have them.
+    pub fn bits(&self) -> Option<&BitBoard> {
+        match &self.squares {
+            Squares::Bits(bits) => Some(bits),
+            Squares::Grid(_) => None,
+        }
+    }
+
+    fn index(&self, pos: &Position) -> usize {
+        pos.row * self.size() + pos.col
+    }
+
+    // Whether `pos` is on the board and pieces may stand on it
+    fn holds(&self, pos: &Position) -> bool {
+        pos.row < self.size() && pos.col < self.size() && self.rules.is_playable(pos)
+    }
+
+    /// The piece on `pos`. Squares off the board, or ones pieces cannot
+    /// stand on, are always empty.
     pub fn get_square(&self, pos: &Position) -> Option<&PieceData> {
-        self.squares[pos.row][pos.col].as_ref()
+        if !self.holds(pos) {
+            return None;
+        }
+        match &self.squares {
+            Squares::Bits(bits) => bits.get(pos).map(piece_ref),
+            Squares::Grid(squares) => squares[self.index(pos)].as_ref(),
+        }
+    }
+
+    /// Puts `piece` on `pos`, or clears it.
+    ///
+    /// # Panics
+    ///
+    /// If `pos` is off the board or pieces cannot stand on it.
+    pub fn set_square(&mut self, pos: &Position, piece: Option<PieceData>) {
+        assert!(
+            self.holds(pos),
+            "{pos:?} is not a playable square of the board"
+        );
+        let index = self.index(pos);
+        if let Some(old) = self.get_square(pos) {
+            self.hash ^= zobrist::piece_key(index, old);
+        }
+        if let Some(new) = &piece {
+            self.hash ^= zobrist::piece_key(index, new);
+        }
+        match &mut self.squares {
+            Squares::Bits(bits) => bits.set(pos, piece),
+            Squares::Grid(squares) => squares[index] = piece,
+        }
     }
 
     pub fn all_pieces_of_side(&self, side: Side) -> Vec<PieceInstance> {
-        let mut instances = Vec::new();
-        for row in 0..8 {
-            for col in 0..8 {
-                if let Some(data) = &self.squares[row][col] {
-                    if data.owner == side {
-                        instances.push(PieceInstance {
-                            position: Position { row, col },
-                            data: *data,
-                        });
-                    }
+        self.pieces_of_side(side).collect()
+    }
+
+    /// Pieces of `side`, row by row from the top left.
+    pub fn pieces_of_side(&self, side: Side) -> impl Iterator<Item = PieceInstance> + '_ {
+        let (bits, grid) = match &self.squares {
+            Squares::Bits(bits) => (Some(bits), None),
+            Squares::Grid(squares) => (None, Some(squares)),
+        };
+
+        let from_bits = bits.into_iter().flat_map(move |bits| {
+            BitBoard::squares(bits.pieces(side)).map(move |bit| PieceInstance {
+                position: BitBoard::position(bit),
+                data: PieceData {
+                    owner: side,
+                    is_king: bits.kings & bit != 0,
+                },
+            })
+        });
+
+        let size = self.size();
+        let from_grid = grid.into_iter().flat_map(move |squares| {
+            squares
+                .iter()
+                .enumerate()
+                .filter_map(move |(index, square)| match square {
+                    Some(data) if data.owner == side => Some(PieceInstance {
+                        position: Position {
+                            row: index / size,
+                            col: index % size,
+                        },
+                        data: *data,
+                    }),
+                    _ => None,
+                })
+        });
+
+        from_bits.chain(from_grid)
+    }
+
+    /// Plays `mv` and returns what `unmake_move` needs to take it back.
+    pub fn apply_move(&mut self, mv: &Move) -> Result<MoveUndo<R>, MoveError> {
+        let mut state = self.state;
+        self.rules.record_move(&mut state, self, mv);
+
+        let piece = match self.get_square(&mv.from) {
+            Some(p) => *p,
+            None => return Err(MoveError::IncorrectStartPosition),
+        };
+
+        let mut undo = MoveUndo {
+            squares: match &self.squares {
+                Squares::Bits(bits) => Restore::Bits(*bits),
+                Squares::Grid(_) => Restore::Grid {
+                    piece,
+                    taken: vec![],
+                },
+            },
+            state: self.state,
+            hash: self.hash,
+        };
+
+        self.set_square(&mv.from, None);
+        self.state = state;
+
+        // Remove whatever the move jumped over before the piece lands, since
+        // a chain may end on a square it took a piece from
+        match &mv.move_type {
+            MoveType::Move => {}
+            // The piece that moved is the one forfeited
+            MoveType::Huffed(huffed) if *huffed == mv.to => return Ok(undo),
+            MoveType::Huffed(huffed) => self.remove(huffed, &mut undo),
+            MoveType::Jump => {
+                for taken in mv.from.squares_between(&mv.to) {
+                    self.remove(&taken, &mut undo);
+                }
+            }
+            MoveType::Capture(sequence) => {
+                for taken in sequence.captured() {
+                    self.remove(taken, &mut undo);
                 }
             }
         }
-        instances
-    }
 
-    pub fn apply_move(&mut self, mv: &Move) -> Result<(), MoveError> {
-        let piece = match self.squares[mv.from.row][mv.from.col].take() {
-            Some(p) => p,
-            None => return Err(MoveError::IncorrectStartPosition),
+        let promotion_row = self.rules.promotion_row(piece.owner);
+        let crowned = match &mv.move_type {
+            MoveType::Capture(sequence) if self.rules.promotes_mid_capture() => {
+                sequence.path().iter().any(|pos| pos.row == promotion_row)
+            }
+            _ => mv.to.row == promotion_row,
         };
 
         let piece = PieceData {
             owner: piece.owner,
-            is_king: piece.is_king
-                || (mv.to.row == 0 && piece.owner == Side::Player)
-                || (mv.to.row == 7 && piece.owner == Side::AI),
+            is_king: piece.is_king || crowned,
         };
+        self.set_square(&mv.to, Some(piece));
 
-        self.squares[mv.to.row][mv.to.col] = Some(piece);
+        Ok(undo)
+    }
 
-        // Only remove the captured piece if this is a jump move
-        if mv.move_type == MoveType::Jump {
-            let take_row = (mv.from.row + mv.to.row) / 2;
-            let take_col = (mv.from.col + mv.to.col) / 2;
-            self.squares[take_row][take_col] = None;
+    fn remove(&mut self, pos: &Position, undo: &mut MoveUndo<R>) {
+        if let Restore::Grid { taken, .. } = &mut undo.squares
+            && let Some(piece) = self.get_square(pos)
+        {
+            taken.push((*pos, *piece));
         }
+        self.set_square(pos, None);
+    }
 
-        Ok(())
+    /// Takes back `mv`, which must be the last move played with
+    /// `apply_move`, putting back what it captured and uncrowning the piece
+    /// if the move crowned it.
+    pub fn unmake_move(&mut self, mv: &Move, undo: MoveUndo<R>) {
+        self.state = undo.state;
+        match undo.squares {
+            Restore::Bits(bits) => self.squares = Squares::Bits(bits),
+            Restore::Grid { piece, taken } => {
+                // The moved piece goes first, as it may stand where one it
+                // took did
+                self.set_square(&mv.to, None);
+                for (pos, taken) in taken {
+                    self.set_square(&pos, Some(taken));
+                }
+                self.set_square(&mv.from, Some(piece));
+            }
+        }
+        self.hash = undo.hash;
     }
 
     pub fn score_of_side(&self, side: Side) -> i16 {
@@ -1,14 +1,122 @@
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub struct MoveDirection {
     pub hor: MoveHorizontal,
     pub ver: MoveVertical,
 }
 
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum MoveHorizontal {
     Left,
     Right,
+    Stay,
 }
 
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum MoveVertical {
     Up,
     Down,
+    Stay,
+}
+
+const UP_LEFT: MoveDirection = MoveDirection {
+    hor: MoveHorizontal::Left,
+    ver: MoveVertical::Up,
+};
+const UP_RIGHT: MoveDirection = MoveDirection {
+    hor: MoveHorizontal::Right,
+    ver: MoveVertical::Up,
+};
+const DOWN_LEFT: MoveDirection = MoveDirection {
+    hor: MoveHorizontal::Left,
+    ver: MoveVertical::Down,
+};
+const DOWN_RIGHT: MoveDirection = MoveDirection {
+    hor: MoveHorizontal::Right,
+    ver: MoveVertical::Down,
+};
+const UP: MoveDirection = MoveDirection::straight(MoveVertical::Up);
+const DOWN: MoveDirection = MoveDirection::straight(MoveVertical::Down);
+const LEFT: MoveDirection = MoveDirection {
+    hor: MoveHorizontal::Left,
+    ver: MoveVertical::Stay,
+};
+const RIGHT: MoveDirection = MoveDirection {
+    hor: MoveHorizontal::Right,
+    ver: MoveVertical::Stay,
+};
+
+impl MoveDirection {
+    /// The two diagonals heading `ver`.
+    pub fn diagonals(ver: MoveVertical) -> &'static [MoveDirection] {
+        match ver {
+            MoveVertical::Up => &[UP_LEFT, UP_RIGHT],
+            MoveVertical::Down => &[DOWN_LEFT, DOWN_RIGHT],
+            MoveVertical::Stay => &[],
+        }
+    }
+
+    pub fn all_diagonals() -> &'static [MoveDirection] {
+        &[DOWN_LEFT, DOWN_RIGHT, UP_LEFT, UP_RIGHT]
+    }
+
+    /// Straight ahead towards `ver`.
+    pub const fn straight(ver: MoveVertical) -> MoveDirection {
+        MoveDirection {
+            hor: MoveHorizontal::Stay,
+            ver,
+        }
+    }
+
+    /// Left and right along the row.
+    pub fn sideways() -> &'static [MoveDirection] {
+        &[LEFT, RIGHT]
+    }
+
+    /// Straight ahead towards `ver`, then left and right along the row.
+    pub fn straight_and_sideways(ver: MoveVertical) -> &'static [MoveDirection] {
+        match ver {
+            MoveVertical::Up => &[UP, LEFT, RIGHT],
+            MoveVertical::Down => &[DOWN, LEFT, RIGHT],
+            MoveVertical::Stay => Self::sideways(),
+        }
+    }
+
+    pub fn all_orthogonals() -> &'static [MoveDirection] {
+        &[DOWN, UP, LEFT, RIGHT]
+    }
+
+    /// The direction leading straight back.
+    pub fn opposite(&self) -> MoveDirection {
+        MoveDirection {
+            hor: self.hor.opposite(),
+            ver: self.ver.opposite(),
+        }
+    }
+
+    /// Every diagonal, then every orthogonal.
+    pub fn all_lines() -> &'static [MoveDirection] {
+        &[
+            DOWN_LEFT, DOWN_RIGHT, UP_LEFT, UP_RIGHT, DOWN, UP, LEFT, RIGHT,
+        ]
+    }
+}
+
+impl MoveHorizontal {
+    pub fn opposite(&self) -> MoveHorizontal {
+        match self {
+            MoveHorizontal::Left => MoveHorizontal::Right,
+            MoveHorizontal::Right => MoveHorizontal::Left,
+            MoveHorizontal::Stay => MoveHorizontal::Stay,
+        }
+    }
+}
+
+impl MoveVertical {
+    pub fn opposite(&self) -> MoveVertical {
+        match self {
+            MoveVertical::Up => MoveVertical::Down,
+            MoveVertical::Down => MoveVertical::Up,
+            MoveVertical::Stay => MoveVertical::Stay,
+        }
+    }
 }
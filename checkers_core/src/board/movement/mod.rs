@@ -9,10 +9,141 @@ pub struct Move {
     pub move_type: MoveType,
 }
 
+impl Move {
+    pub fn is_capture(&self) -> bool {
+        matches!(self.move_type, MoveType::Jump | MoveType::Capture(_))
+    }
+
+    /// Squares of the pieces a capture chain takes, in the order they are
+    /// jumped. A single `Jump` does not record the piece it takes, since
+    /// for a flying king that depends on the board, so it gives none: the
+    /// piece is on one of `from.squares_between(&to)`.
+    pub fn captured(&self) -> &[Position] {
+        match &self.move_type {
+            MoveType::Capture(sequence) => sequence.captured(),
+            _ => &[],
+        }
+    }
+}
+
+// Capture chains are kept in place on purpose, so that listing them does
+// not allocate
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum MoveType {
     Move,
+    /// A single hop over one enemy piece.
     Jump,
+    /// A complete capture chain, played as one move.
+    Capture(CaptureSequence),
+    /// A plain move made while a capture was on offer, under huffing rules.
+    /// The piece on the given square is forfeited once the move is made:
+    /// the moving piece, on its new square, if it could have captured, and
+    /// otherwise the piece with the longest capture, the first of them row
+    /// by row from the top left on a tie.
+    Huffed(Position),
+}
+
+/// Every landing square of a capture chain (ending with the move's `to`)
+/// and the squares of the pieces taken, in the order they were jumped, one
+/// of each per jump. Chains of up to `CaptureSequence::INLINE_JUMPS` jumps
+/// are kept inside the move, so listing them allocates nothing.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CaptureSequence {
+    pub(crate) path: SquareList,
+    pub(crate) captured: SquareList,
+}
+
+impl CaptureSequence {
+    /// Longest chain kept without a heap allocation.
+    pub const INLINE_JUMPS: usize = INLINE_SQUARES;
+
+    /// A chain landing on `path` and taking `captured`, which must be as
+    /// long as each other.
+    pub fn new(path: &[Position], captured: &[Position]) -> Self {
+        assert_eq!(path.len(), captured.len(), "one piece is taken per jump");
+        CaptureSequence {
+            path: SquareList::from_slice(path),
+            captured: SquareList::from_slice(captured),
+        }
+    }
+
+    pub fn path(&self) -> &[Position] {
+        self.path.as_slice()
+    }
+
+    pub fn captured(&self) -> &[Position] {
+        self.captured.as_slice()
+    }
+}
+
+const INLINE_SQUARES: usize = 8;
+
+// A short list of squares kept in place, moving to the heap only once it
+// outgrows its room. Lists compare by their squares, wherever they are kept.
+#[derive(Debug, Clone)]
+pub(crate) enum SquareList {
+    Inline {
+        len: usize,
+        squares: [Position; INLINE_SQUARES],
+    },
+    Heap(Vec<Position>),
+}
+
+impl SquareList {
+    fn from_slice(squares: &[Position]) -> Self {
+        let mut list = Self::default();
+        for pos in squares {
+            list.push(*pos);
+        }
+        list
+    }
+
+    pub(crate) fn as_slice(&self) -> &[Position] {
+        match self {
+            SquareList::Inline { len, squares } => &squares[..*len],
+            SquareList::Heap(squares) => squares,
+        }
+    }
+
+    pub(crate) fn push(&mut self, pos: Position) {
+        match self {
+            SquareList::Inline { len, squares } if *len < INLINE_SQUARES => {
+                squares[*len] = pos;
+                *len += 1;
+            }
+            SquareList::Inline { squares, .. } => {
+                let mut spilled = squares.to_vec();
+                spilled.push(pos);
+                *self = SquareList::Heap(spilled);
+            }
+            SquareList::Heap(squares) => squares.push(pos),
+        }
+    }
+
+    pub(crate) fn pop(&mut self) {
+        match self {
+            SquareList::Inline { len, .. } => *len = len.saturating_sub(1),
+            SquareList::Heap(squares) => {
+                squares.pop();
+            }
+        }
+    }
+}
+
+impl Default for SquareList {
+    fn default() -> Self {
+        SquareList::Inline {
+            len: 0,
+            squares: [Position { row: 0, col: 0 }; INLINE_SQUARES],
+        }
+    }
+}
+
+impl PartialEq for SquareList {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
 }
 
 #[derive(Debug)]
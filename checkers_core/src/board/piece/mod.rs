@@ -4,7 +4,7 @@ use crate::Position;
 
 use self::side::Side;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub struct PieceData {
     pub owner: Side,
     pub is_king: bool,
@@ -1,14 +1,16 @@
-#[derive(Copy, Clone, PartialEq, Debug)]
+/// The colour of a player's pieces. Dark starts at the bottom of the board
+/// and moves first; who controls each colour is up to the `GameManager`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum Side {
-    Player,
-    AI,
+    Dark,
+    Light,
 }
 
 impl Side {
     pub fn opposite(&self) -> Side {
         match self {
-            Side::Player => Side::AI,
-            Side::AI => Side::Player,
+            Side::Dark => Side::Light,
+            Side::Light => Side::Dark,
         }
     }
 }
@@ -12,14 +12,11 @@ pub struct Position {
 }
 
 impl Position {
-    pub fn setup(row: isize, col: isize) -> Result<Self, PositionError> {
-        if row < 0 || col < 0 || row >= 8 || col >= 8 {
+    pub fn setup(row: isize, col: isize, size: usize) -> Result<Self, PositionError> {
+        let size = size as isize;
+        if row < 0 || col < 0 || row >= size || col >= size {
             return Err(PositionError::OutOfBounds);
         }
-        if (row + col) % 2 != 1 {
-            return Err(PositionError::NotBlack);
-        }
-
         Ok(Position {
             row: row as usize,
             col: col as usize,
@@ -30,17 +27,38 @@ impl Position {
         &self,
         dir: &MoveDirection,
         steps: usize,
+        size: usize,
     ) -> Result<Position, PositionError> {
         let delta_row: isize = match dir.ver {
             MoveVertical::Up => -(steps as isize),
             MoveVertical::Down => steps as isize,
+            MoveVertical::Stay => 0,
         };
 
         let delta_col: isize = match dir.hor {
             MoveHorizontal::Left => -(steps as isize),
             MoveHorizontal::Right => steps as isize,
+            MoveHorizontal::Stay => 0,
         };
 
-        Self::setup(self.row as isize + delta_row, self.col as isize + delta_col)
+        Self::setup(
+            self.row as isize + delta_row,
+            self.col as isize + delta_col,
+            size,
+        )
+    }
+
+    /// Squares strictly between `self` and `other`, which must share a line.
+    pub fn squares_between(&self, other: &Position) -> Vec<Position> {
+        let delta_row = other.row as isize - self.row as isize;
+        let delta_col = other.col as isize - self.col as isize;
+        let steps = delta_row.abs().max(delta_col.abs());
+
+        (1..steps)
+            .map(|step| Position {
+                row: (self.row as isize + delta_row.signum() * step) as usize,
+                col: (self.col as isize + delta_col.signum() * step) as usize,
+            })
+            .collect()
     }
 }
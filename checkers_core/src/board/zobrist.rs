@@ -0,0 +1,61 @@
+use std::hash::{Hash, Hasher};
+
+use super::piece::PieceData;
+use super::piece::side::Side;
+
+// Every key is drawn from one SplitMix64 stream with a fixed seed, so that
+// hashes stay the same between runs and builds. Working a key out from its
+// place in the stream needs no table, so boards of any size are covered.
+const SEED: u64 = 0x2545_F491_4F6C_DD1D;
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Mixed into the hash when light is to move.
+pub(crate) const LIGHT_TO_MOVE: u64 = mix(SEED);
+
+// The `index`th value of the stream
+const fn key(index: u64) -> u64 {
+    mix(SEED.wrapping_add(index.wrapping_add(1).wrapping_mul(GOLDEN_GAMMA)))
+}
+
+const fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// The key for `piece` standing on the square numbered `square`: one per
+/// square for each of a dark man, a dark king, a light man and a light king.
+pub(crate) fn piece_key(square: usize, piece: &PieceData) -> u64 {
+    let side = match piece.owner {
+        Side::Dark => 0,
+        Side::Light => 2,
+    };
+    key(square as u64 * 4 + side + piece.is_king as u64)
+}
+
+/// The key for the variant state of a board. The default state, which is
+/// all most variants ever have, keys to zero.
+pub(crate) fn state_key<S: Hash + Default + PartialEq>(state: &S) -> u64 {
+    if *state == S::default() {
+        return 0;
+    }
+    let mut hasher = StateHasher(SEED);
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Feeds the bytes of a state through the same mix as the keys. The standard
+// library's hashers may change between releases.
+struct StateHasher(u64);
+
+impl Hasher for StateHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = mix((self.0 ^ *byte as u64).wrapping_add(GOLDEN_GAMMA));
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -1,34 +1,397 @@
-use crate::ai::get_best_move;
-use crate::board::movement::{Move, MoveError, MoveType};
+use crate::ai::TranspositionTable;
+use crate::ai::minimax::{MAX_DEPTH, analyse_with, best_of};
+use crate::board::movement::{CaptureSequence, Move, MoveError, MoveType};
 use crate::board::piece::PieceInstance;
 use crate::board::piece::side::Side;
 use crate::board::position::Position;
-use crate::board::{Board, BoardError};
-use crate::move_controller::{moves_per_piece, moves_per_side};
+use crate::board::{Board, BoardError, MoveUndo};
+use crate::move_controller::{has_any_move, moves_per_piece};
+use crate::rules::{EnglishDraughts, RuleSet};
 
 #[derive(Clone, Debug)]
-pub struct GameManager {
-    pub board: Board,
+pub struct GameManager<R: RuleSet = EnglishDraughts> {
+    pub board: Board<R>,
     pub current_turn: Side,
-    pub game_over: bool,
+    pub result: Option<GameResult>,
+    pub pending_capture: Option<PendingCapture<R>>,
+    pub draw_rules: DrawRules,
+    /// Side whose draw offer is waiting for an answer. It lapses once that
+    /// side makes its next move.
+    pub draw_offer: Option<Side>,
+    dark_controller: Controller,
+    light_controller: Controller,
+    // Zobrist keys of every position of the game, the current one last
+    positions: Vec<u64>,
+    // Where the positions since the last capture or man move start
+    quiet_start: usize,
+    // Plies since the last capture or man move
+    quiet_plies: usize,
+    // Moves played so far, one entry per turn
+    history: Vec<Move>,
+    // How to take back each move given to `make_move`, most recent last
+    steps: Vec<Step<R>>,
+    // Moves taken back, most recent last
+    undone: Vec<Move>,
+    // Positions the computer has searched, kept from one move to the next
+    table: TranspositionTable,
+}
+
+// One move given to `make_move`, with what it changed so that it can be
+// taken back without keeping a copy of the board.
+#[derive(Clone, Debug)]
+struct Step<R: RuleSet> {
+    // The move as it was given, to be played again by `redo`
+    mv: Move,
+    // The move made on the board: the whole chain if `mv` finished one
+    played: Move,
+    undo: MoveUndo<R>,
+    current_turn: Side,
+    result: Option<GameResult>,
+    pending_capture: Option<PendingCapture<R>>,
+    draw_offer: Option<Side>,
+    positions: usize,
+    quiet_start: usize,
+    quiet_plies: usize,
+}
+
+/// Who makes the moves for one side.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Controller {
+    Human,
+    Computer,
+}
+
+/// How a finished game ended. `winner` is `None` for a draw.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GameResult {
     pub winner: Option<Side>,
+    pub reason: GameEndReason,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GameEndReason {
+    NoMoves,
+    Repetition,
+    MoveLimit,
+    Resignation,
+    Agreement,
+}
+
+/// When a game is drawn without either side agreeing to it.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DrawRules {
+    /// Times the same position, with the same side to move, has to occur.
+    pub repetitions: usize,
+    /// Moves per side without a capture or a man moving, e.g. 40 under the
+    /// ACF rules. `None` turns the rule off.
+    pub move_limit: Option<usize>,
+}
+
+impl Default for DrawRules {
+    fn default() -> Self {
+        DrawRules {
+            repetitions: 3,
+            move_limit: Some(40),
+        }
+    }
+}
+
+/// A capture chain that has been started one jump at a time and is not
+/// finished yet. Only the piece standing on `position` may move until it is.
+#[derive(Clone, Debug)]
+pub struct PendingCapture<R: RuleSet = EnglishDraughts> {
+    pub position: Position,
+    path: Vec<Position>,
+    chains: Vec<Move>,
+    // Jumps made so far, with how to take each back off the board
+    hops: Vec<(Move, MoveUndo<R>)>,
+}
+
+impl<R: RuleSet> PendingCapture<R> {
+    // Searches the chains still open from the turn's starting position and
+    // returns what is left of the best one, to be played in one go. `board`
+    // is the position after the jumps made so far.
+    fn best_rest(
+        &self,
+        board: &Board<R>,
+        side: Side,
+        table: &mut TranspositionTable,
+    ) -> Result<Move, GameError> {
+        let mut start = board.clone();
+        for (hop, undo) in self.hops.iter().rev() {
+            start.unmake_move(hop, undo.clone());
+        }
+
+        let (chain, _) = best_of(&start, side, self.chains.clone(), MAX_DEPTH, table)
+            .ok_or(GameError::NoMovesAvailable)?;
+        let MoveType::Capture(sequence) = chain.move_type else {
+            return Err(GameError::NoMovesAvailable);
+        };
+
+        let done = self.path.len();
+        Ok(Move {
+            from: self.position,
+            to: chain.to,
+            move_type: MoveType::Capture(CaptureSequence::new(
+                &sequence.path()[done..],
+                &sequence.captured()[done..],
+            )),
+        })
+    }
+
+    fn next_jumps(&self) -> Vec<Move> {
+        let mut jumps: Vec<Move> = vec![];
+        for chain in &self.chains {
+            if let MoveType::Capture(sequence) = &chain.move_type {
+                let jump = Move {
+                    from: self.position,
+                    to: sequence.path()[self.path.len()],
+                    move_type: MoveType::Jump,
+                };
+                if !jumps.contains(&jump) {
+                    jumps.push(jump);
+                }
+            }
+        }
+        jumps
+    }
 }
 
 impl GameManager {
     pub fn new() -> Self {
+        Self::from_board(Board::setup(), Side::Dark)
+    }
+}
+
+impl<R: RuleSet> GameManager<R> {
+    pub fn with_rules(rules: R) -> Self {
+        Self::from_board(Board::setup_with(rules), Side::Dark)
+    }
+
+    pub fn from_board(board: Board<R>, current_turn: Side) -> Self {
         GameManager {
-            board: Board::setup(),
-            current_turn: Side::Player,
-            game_over: false,
-            winner: None,
+            positions: vec![board.zobrist(current_turn)],
+            quiet_start: 0,
+            board,
+            current_turn,
+            result: None,
+            pending_capture: None,
+            draw_rules: DrawRules::default(),
+            draw_offer: None,
+            dark_controller: Controller::Human,
+            light_controller: Controller::Computer,
+            quiet_plies: 0,
+            history: vec![],
+            steps: vec![],
+            undone: vec![],
+            table: TranspositionTable::default(),
+        }
+    }
+
+    pub fn with_draw_rules(mut self, draw_rules: DrawRules) -> Self {
+        self.draw_rules = draw_rules;
+        self
+    }
+
+    /// Gives the computer's search a transposition table with room for
+    /// `entries` positions.
+    pub fn with_table_size(mut self, entries: usize) -> Self {
+        self.table.resize(entries);
+        self
+    }
+
+    /// Forgets every position the computer has searched, e.g. before the
+    /// board is set up for another game.
+    pub fn clear_search_table(&mut self) {
+        self.table.clear();
+    }
+
+    pub fn search_table(&self) -> &TranspositionTable {
+        &self.table
+    }
+
+    /// Hands each side to a human or to the computer. By default a human
+    /// plays dark against the computer.
+    pub fn with_controllers(mut self, dark: Controller, light: Controller) -> Self {
+        self.dark_controller = dark;
+        self.light_controller = light;
+        self
+    }
+
+    pub fn controller(&self, side: Side) -> Controller {
+        match side {
+            Side::Dark => self.dark_controller,
+            Side::Light => self.light_controller,
+        }
+    }
+
+    pub fn set_controller(&mut self, side: Side, controller: Controller) {
+        match side {
+            Side::Dark => self.dark_controller = controller,
+            Side::Light => self.light_controller = controller,
+        }
+    }
+
+    /// Whether the side to move is played by the computer.
+    pub fn is_computer_turn(&self) -> bool {
+        self.controller(self.current_turn) == Controller::Computer
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.result.is_some()
+    }
+
+    pub fn resign(&mut self, side: Side) -> Result<(), GameError> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+
+        self.finish(Some(side.opposite()), GameEndReason::Resignation);
+        Ok(())
+    }
+
+    /// Offers a draw on behalf of `side`. Offering while the opponent's own
+    /// offer stands accepts it.
+    pub fn offer_draw(&mut self, side: Side) -> Result<(), GameError> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+
+        if self.draw_offer == Some(side.opposite()) {
+            return self.accept_draw(side);
         }
+        self.draw_offer = Some(side);
+        Ok(())
+    }
+
+    /// Accepts the opponent's draw offer on behalf of `side`.
+    pub fn accept_draw(&mut self, side: Side) -> Result<(), GameError> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+        if self.draw_offer != Some(side.opposite()) {
+            return Err(GameError::NoDrawOffer);
+        }
+
+        self.finish(None, GameEndReason::Agreement);
+        Ok(())
+    }
+
+    /// Turns down the opponent's draw offer on behalf of `side`.
+    pub fn decline_draw(&mut self, side: Side) -> Result<(), GameError> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+        if self.draw_offer != Some(side.opposite()) {
+            return Err(GameError::NoDrawOffer);
+        }
+
+        self.draw_offer = None;
+        Ok(())
+    }
+
+    fn finish(&mut self, winner: Option<Side>, reason: GameEndReason) {
+        self.result = Some(GameResult { winner, reason });
+        self.draw_offer = None;
+    }
+
+    /// Moves played so far, oldest first, one per turn. A capture entered
+    /// one jump at a time shows up as the whole chain once it is finished,
+    /// so the history can be replayed with `Board::apply_move`.
+    pub fn history(&self) -> &[Move] {
+        &self.history
+    }
+
+    /// Takes back the last move, restoring the position, the turn, any
+    /// unfinished capture, draw offers and the result as they were before it.
+    /// A capture entered one jump at a time is taken back a jump at a time.
+    pub fn undo(&mut self) -> Option<Move> {
+        let step = self.steps.pop()?;
+        let ended_turn = self.pending_capture.is_none();
+        if ended_turn {
+            self.history.pop();
+        }
+
+        self.board.unmake_move(&step.played, step.undo);
+        let mut pending_capture = step.pending_capture;
+        if ended_turn && let Some(pending) = &mut pending_capture {
+            // The chain was taken back whole; the jumps before its last one
+            // go back on
+            for (hop, undo) in &mut pending.hops {
+                if let Ok(redone) = self.board.apply_move(hop) {
+                    *undo = redone;
+                }
+            }
+        }
+
+        self.current_turn = step.current_turn;
+        self.result = step.result;
+        self.pending_capture = pending_capture;
+        self.draw_offer = step.draw_offer;
+        self.positions.truncate(step.positions);
+        self.quiet_start = step.quiet_start;
+        self.quiet_plies = step.quiet_plies;
+        self.undone.push(step.mv.clone());
+        Some(step.mv)
+    }
+
+    /// Plays the last move taken back by `undo` again.
+    pub fn redo(&mut self) -> Option<Move> {
+        let mv = self.undone.pop()?;
+        if self.step(mv.clone()).is_err() {
+            self.undone.push(mv);
+            return None;
+        }
+        Some(mv)
     }
 
     pub fn make_move(&mut self, mv: Move) -> Result<(), GameError> {
-        if self.game_over {
+        self.step(mv)?;
+        self.undone.clear();
+        Ok(())
+    }
+
+    // Plays `mv` and keeps what it takes to undo it
+    fn step(&mut self, mv: Move) -> Result<(), GameError> {
+        let current_turn = self.current_turn;
+        let result = self.result;
+        let pending_capture = self.pending_capture.clone();
+        let draw_offer = self.draw_offer;
+        let positions = self.positions.len();
+        let quiet_start = self.quiet_start;
+        let quiet_plies = self.quiet_plies;
+
+        let (played, undo) = self.play(&mv)?;
+        if self.pending_capture.is_none() {
+            self.history.push(played.clone());
+        }
+        self.steps.push(Step {
+            mv,
+            played,
+            undo,
+            current_turn,
+            result,
+            pending_capture,
+            draw_offer,
+            positions,
+            quiet_start,
+            quiet_plies,
+        });
+        Ok(())
+    }
+
+    // Plays `mv`, returning the move made on the board and how to take it back
+    fn play(&mut self, mv: &Move) -> Result<(Move, MoveUndo<R>), GameError> {
+        if self.is_over() {
             return Err(GameError::GameOver);
         }
 
+        if let Some(pending) = &self.pending_capture
+            && mv.from != pending.position
+        {
+            return Err(GameError::CaptureInProgress);
+        }
+
         let pos = mv.from;
         let data = self.board.get_square(&pos).ok_or(GameError::InvalidMove)?;
         let piece = PieceInstance {
@@ -39,41 +402,137 @@ impl GameManager {
             return Err(GameError::NotYourTurn);
         }
 
+        if mv.move_type == MoveType::Jump || self.pending_capture.is_some() {
+            return self.continue_capture(&piece, mv);
+        }
+
         let possible_moves = moves_per_piece(&self.board, &piece);
-        if !possible_moves.contains(&mv) {
+        if !possible_moves.contains(mv) {
             return Err(GameError::InvalidMove);
         }
 
-        self.board.apply_move(&mv)?;
+        let undo = self.board.apply_move(mv)?;
+        self.end_turn(piece.data.is_king && mv.move_type == MoveType::Move);
 
-        if mv.move_type == MoveType::Jump {
-            let remaining_moves = moves_per_side(&self.board, self.current_turn);
-            if remaining_moves
-                .iter()
-                .any(|m| m.move_type == MoveType::Jump)
-            {
-                return Ok(());
+        Ok((mv.clone(), undo))
+    }
+
+    // Plays a single jump, or the rest of a chain, for the piece that is
+    // capturing this turn. The turn only ends once a full chain is complete.
+    fn continue_capture(
+        &mut self,
+        piece: &PieceInstance,
+        mv: &Move,
+    ) -> Result<(Move, MoveUndo<R>), GameError> {
+        let mut pending = match &self.pending_capture {
+            Some(pending) => pending.clone(),
+            None => PendingCapture {
+                position: piece.position,
+                path: vec![],
+                chains: moves_per_piece(&self.board, piece)
+                    .into_iter()
+                    .filter(|m| m.is_capture())
+                    .collect(),
+                hops: vec![],
+            },
+        };
+
+        match &mv.move_type {
+            MoveType::Move | MoveType::Huffed(_) => return Err(GameError::InvalidMove),
+            MoveType::Jump => pending.path.push(mv.to),
+            MoveType::Capture(sequence) => pending.path.extend(sequence.path()),
+        }
+
+        pending.chains.retain(|chain| match &chain.move_type {
+            MoveType::Capture(sequence) => sequence.path().starts_with(&pending.path),
+            _ => false,
+        });
+
+        let finished = pending.chains.iter().find(|chain| {
+            let MoveType::Capture(sequence) = &chain.move_type else {
+                return false;
+            };
+            sequence.path().len() == pending.path.len()
+                && sequence.captured().ends_with(mv.captured())
+        });
+
+        if let Some(chain) = finished {
+            // The chain is played whole from where the turn started
+            let chain = chain.clone();
+            for (hop, undo) in pending.hops.into_iter().rev() {
+                self.board.unmake_move(&hop, undo);
             }
+            let undo = self.board.apply_move(&chain)?;
+            self.pending_capture = None;
+            self.end_turn(false);
+            return Ok((chain, undo));
         }
 
-        self.current_turn = match self.current_turn {
-            Side::Player => Side::AI,
-            Side::AI => Side::Player,
-        };
+        // A partial chain can only be entered one jump at a time
+        if pending.chains.is_empty() || mv.move_type != MoveType::Jump {
+            return Err(GameError::InvalidMove);
+        }
+
+        let undo = self.board.apply_move(mv)?;
+        pending.hops.push((mv.clone(), undo.clone()));
+        pending.position = mv.to;
+        self.pending_capture = Some(pending);
+
+        Ok((mv.clone(), undo))
+    }
 
-        let opponent_moves = moves_per_side(&self.board, self.current_turn);
-        if opponent_moves.is_empty() {
-            self.game_over = true;
-            self.winner = Some(match self.current_turn {
-                Side::Player => Side::AI,
-                Side::AI => Side::Player,
-            });
+    // Hands the turn over and checks whether the game has ended. A quiet
+    // move is a plain king move: it can be undone, so positions may repeat.
+    fn end_turn(&mut self, quiet: bool) {
+        if self.draw_offer == Some(self.current_turn) {
+            self.draw_offer = None;
         }
+        self.current_turn = self.current_turn.opposite();
 
-        Ok(())
+        if quiet {
+            self.quiet_plies += 1;
+        } else {
+            self.quiet_plies = 0;
+            self.quiet_start = self.positions.len();
+        }
+        self.positions.push(self.board.zobrist(self.current_turn));
+
+        if !has_any_move(&self.board, self.current_turn) {
+            let winner = if self.board.rules().losing_game() {
+                self.current_turn
+            } else {
+                self.current_turn.opposite()
+            };
+            self.finish(Some(winner), GameEndReason::NoMoves);
+            return;
+        }
+
+        let current = self.board.zobrist(self.current_turn);
+        let repetitions = self.positions[self.quiet_start..]
+            .iter()
+            .filter(|key| **key == current)
+            .count();
+        if repetitions >= self.draw_rules.repetitions {
+            self.finish(None, GameEndReason::Repetition);
+            return;
+        }
+
+        if let Some(limit) = self.draw_rules.move_limit
+            && self.quiet_plies >= 2 * limit
+        {
+            self.finish(None, GameEndReason::MoveLimit);
+        }
     }
 
     pub fn get_possible_moves(&self, pos: Position) -> Vec<Move> {
+        if let Some(pending) = &self.pending_capture {
+            return if pending.position == pos {
+                pending.next_jumps()
+            } else {
+                vec![]
+            };
+        }
+
         if let Some(data) = self.board.get_square(&pos) {
             let piece = PieceInstance {
                 position: pos,
@@ -90,27 +549,42 @@ impl GameManager {
     }
 
     pub fn make_ai_move(&mut self) -> Result<(), GameError> {
-        if self.game_over {
+        if self.is_over() {
             return Err(GameError::GameOver);
         }
 
-        if self.current_turn != Side::AI {
+        if !self.is_computer_turn() {
             return Err(GameError::NotAiTurn);
         }
 
-        let best_move = get_best_move(&self.board, Side::AI).ok_or(GameError::NoMovesAvailable)?;
+        if let Some(pending) = &self.pending_capture {
+            let rest = pending.best_rest(&self.board, self.current_turn, &mut self.table)?;
+            return self.make_move(rest);
+        }
+
+        let (best_move, _) =
+            analyse_with(&self.board, self.current_turn, MAX_DEPTH, &mut self.table)
+                .ok_or(GameError::NoMovesAvailable)?;
 
         self.make_move(best_move)
     }
 }
 
+impl Default for GameManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug)]
 pub enum GameError {
     GameOver,
     NotYourTurn,
     NotAiTurn,
     InvalidMove,
+    CaptureInProgress,
     NoMovesAvailable,
+    NoDrawOffer,
     BoardError(BoardError),
     MoveError(MoveError),
 }
@@ -0,0 +1,244 @@
+use crate::board::Board;
+use crate::board::movement::{Move, MoveError};
+use crate::board::piece::side::Side;
+use crate::move_controller::moves_per_side;
+use crate::rules::{EnglishDraughts, RuleSet};
+
+/// Refers to a node of a `GameTree`. Ids stay valid until their branch is
+/// deleted and are never handed out again afterwards.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NodeId(usize);
+
+#[derive(Clone, Debug)]
+pub struct Node {
+    mv: Option<Move>,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+impl Node {
+    /// The move leading to this node, `None` for the root.
+    pub fn mv(&self) -> Option<&Move> {
+        self.mv.as_ref()
+    }
+
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    /// Replies to this node's move. The first one continues the mainline,
+    /// the rest are variations.
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+}
+
+/// A game record with variations. The root stands for the starting position
+/// and every other node for the move played from its parent's position.
+#[derive(Clone, Debug)]
+pub struct GameTree<R: RuleSet = EnglishDraughts> {
+    start: Board<R>,
+    first_turn: Side,
+    // Deleted nodes leave a hole so that the remaining ids stay valid
+    nodes: Vec<Option<Node>>,
+    current: NodeId,
+}
+
+impl<R: RuleSet> GameTree<R> {
+    pub fn new(start: Board<R>, first_turn: Side) -> Self {
+        GameTree {
+            start,
+            first_turn,
+            nodes: vec![Some(Node {
+                mv: None,
+                parent: None,
+                children: vec![],
+            })],
+            current: NodeId(0),
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub fn current(&self) -> NodeId {
+        self.current
+    }
+
+    pub fn node(&self, id: NodeId) -> Option<&Node> {
+        self.nodes.get(id.0)?.as_ref()
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> Result<&mut Node, TreeError> {
+        self.nodes
+            .get_mut(id.0)
+            .and_then(Option::as_mut)
+            .ok_or(TreeError::UnknownNode)
+    }
+
+    /// Plays `mv` from the current node and moves there. A move that has
+    /// been played from here before is followed instead of added twice; a
+    /// new one becomes the last variation.
+    pub fn play(&mut self, mv: Move) -> Result<NodeId, TreeError> {
+        let current = self.current;
+        let node = self.node(current).ok_or(TreeError::UnknownNode)?;
+        if let Some(child) = node
+            .children
+            .iter()
+            .find(|child| self.node(**child).and_then(Node::mv) == Some(&mv))
+        {
+            let child = *child;
+            self.current = child;
+            return Ok(child);
+        }
+
+        let (board, side) = self.position(current)?;
+        if !moves_per_side(&board, side).contains(&mv) {
+            return Err(TreeError::InvalidMove);
+        }
+
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Some(Node {
+            mv: Some(mv),
+            parent: Some(current),
+            children: vec![],
+        }));
+        self.node_mut(current)?.children.push(id);
+        self.current = id;
+
+        Ok(id)
+    }
+
+    pub fn go_to(&mut self, id: NodeId) -> Result<(), TreeError> {
+        self.node(id).ok_or(TreeError::UnknownNode)?;
+        self.current = id;
+        Ok(())
+    }
+
+    /// Steps back one move. Returns `false` at the root.
+    pub fn back(&mut self) -> bool {
+        match self.node(self.current).and_then(Node::parent) {
+            Some(parent) => {
+                self.current = parent;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Steps forward along the mainline. Returns `false` at the end of it.
+    pub fn forward(&mut self) -> bool {
+        match self
+            .node(self.current)
+            .and_then(|node| node.children.first())
+        {
+            Some(child) => {
+                self.current = *child;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves from the root down to `id`, in the order they were played.
+    pub fn line(&self, id: NodeId) -> Result<Vec<&Move>, TreeError> {
+        let mut line = vec![];
+        let mut node = self.node(id).ok_or(TreeError::UnknownNode)?;
+        while let Some(mv) = node.mv() {
+            line.push(mv);
+            node = node
+                .parent
+                .and_then(|parent| self.node(parent))
+                .ok_or(TreeError::UnknownNode)?;
+        }
+        line.reverse();
+        Ok(line)
+    }
+
+    /// Moves of the mainline, following the first reply from the root on.
+    pub fn mainline(&self) -> Vec<&Move> {
+        let mut line = vec![];
+        let mut node = self.node(self.root());
+        while let Some(child) = node.and_then(|node| node.children.first()) {
+            node = self.node(*child);
+            if let Some(mv) = node.and_then(Node::mv) {
+                line.push(mv);
+            }
+        }
+        line
+    }
+
+    /// The position at `id` and the side to move there, found by replaying
+    /// the line leading to it from the starting position.
+    pub fn position(&self, id: NodeId) -> Result<(Board<R>, Side), TreeError> {
+        let mut board = self.start.clone();
+        let mut side = self.first_turn;
+        for mv in self.line(id)? {
+            board.apply_move(mv)?;
+            side = side.opposite();
+        }
+        Ok((board, side))
+    }
+
+    pub fn current_position(&self) -> Result<(Board<R>, Side), TreeError> {
+        self.position(self.current)
+    }
+
+    /// Makes the line leading to `id` the mainline, moving it in front of
+    /// its siblings at every branch point on the way up.
+    pub fn promote(&mut self, id: NodeId) -> Result<(), TreeError> {
+        let mut child = id;
+        while let Some(parent) = self.node(child).ok_or(TreeError::UnknownNode)?.parent {
+            let children = &mut self.node_mut(parent)?.children;
+            if let Some(index) = children.iter().position(|c| *c == child) {
+                children.remove(index);
+                children.insert(0, child);
+            }
+            child = parent;
+        }
+        Ok(())
+    }
+
+    /// Removes `id` together with every move played after it. If the current
+    /// node goes with it, the tree moves back to where the branch started.
+    pub fn delete(&mut self, id: NodeId) -> Result<(), TreeError> {
+        let parent = self
+            .node(id)
+            .ok_or(TreeError::UnknownNode)?
+            .parent
+            .ok_or(TreeError::RootNode)?;
+        self.node_mut(parent)?.children.retain(|child| *child != id);
+
+        let mut doomed = vec![id];
+        while let Some(next) = doomed.pop() {
+            if next == self.current {
+                self.current = parent;
+            }
+            if let Some(node) = self.nodes[next.0].take() {
+                doomed.extend(node.children);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl GameTree {
+    pub fn setup() -> Self {
+        Self::new(Board::setup(), Side::Dark)
+    }
+}
+
+#[derive(Debug)]
+pub enum TreeError {
+    UnknownNode,
+    RootNode,
+    InvalidMove,
+    MoveError(MoveError),
+}
+
+impl From<MoveError> for TreeError {
+    fn from(e: MoveError) -> Self {
+        TreeError::MoveError(e)
+    }
+}
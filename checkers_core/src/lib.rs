@@ -1,27 +1,47 @@
 pub mod ai;
 pub mod board;
 pub mod game_manager;
+pub mod game_tree;
 pub mod move_controller;
+pub mod rules;
 
-pub use board::{Board, BoardError};
+pub use board::bitboard::BitBoard;
+pub use board::{Board, BoardError, MoveUndo};
 
-pub use game_manager::{GameError, GameManager};
+pub use game_manager::{
+    Controller, DrawRules, GameEndReason, GameError, GameManager, GameResult, PendingCapture,
+};
+pub use game_tree::{GameTree, Node, NodeId, TreeError};
 
 pub use board::piece::PieceData;
 pub use board::piece::side::Side;
 pub use board::position::Position;
 
 pub use board::movement::direction::{MoveDirection, MoveHorizontal, MoveVertical};
-pub use board::movement::{Move, MoveError, MoveType};
+pub use board::movement::{CaptureSequence, Move, MoveError, MoveType};
 
-pub use move_controller::{check_move, moves_per_piece, moves_per_side};
+pub use move_controller::{
+    MoveList, check_move, generate_moves, has_any_move, has_capture, moves_per_piece,
+    moves_per_side,
+};
 
-pub use ai::{decide_move, get_best_move};
+pub use rules::{
+    CanadianCheckers, CasualDraughts, EnglishDraughts, FrisianDraughts, FrisianState,
+    GiveawayDraughts, InternationalDraughts, ItalianDraughts, RuleSet, RussianDraughts,
+    TurkishDraughts,
+};
+
+pub use ai::{Score, TranspositionTable, analyse, analyse_with, decide_move, get_best_move};
 
 pub mod prelude {
     pub use crate::{
-        Board, BoardError, GameError, GameManager, Move, MoveDirection, MoveError, MoveHorizontal,
-        MoveType, MoveVertical, PieceData, Position, Side, check_move, decide_move, get_best_move,
-        moves_per_piece, moves_per_side,
+        BitBoard, Board, BoardError, CanadianCheckers, CaptureSequence, CasualDraughts, Controller,
+        DrawRules, EnglishDraughts, FrisianDraughts, GameEndReason, GameError, GameManager,
+        GameResult, GameTree, GiveawayDraughts, InternationalDraughts, ItalianDraughts, Move,
+        MoveDirection, MoveError, MoveHorizontal, MoveList, MoveType, MoveUndo, MoveVertical, Node,
+        NodeId, PendingCapture, PieceData, Position, RuleSet, RussianDraughts, Score, Side,
+        TranspositionTable, TreeError, TurkishDraughts, analyse, analyse_with, check_move,
+        decide_move, generate_moves, get_best_move, has_any_move, has_capture, moves_per_piece,
+        moves_per_side,
     };
 }
@@ -1,125 +1,736 @@
 use crate::board::Board;
-use crate::board::movement::direction::{MoveDirection, MoveHorizontal, MoveVertical};
-use crate::board::movement::{Move, MoveType};
-use crate::board::piece::PieceInstance;
+use crate::board::bitboard::BitBoard;
+use crate::board::movement::direction::MoveDirection;
+use crate::board::movement::{CaptureSequence, Move, MoveType};
 use crate::board::piece::side::Side;
+use crate::board::piece::{PieceData, PieceInstance};
+use crate::board::position::Position;
+use crate::rules::RuleSet;
+use std::ops::Deref;
 
-pub fn moves_per_side(board: &Board, side: Side) -> Vec<Move> {
-    let pieces = board.all_pieces_of_side(side);
-    let mut moves: Vec<Move> = vec![];
+/// A reusable buffer for the moves of one side. Generating into the same
+/// list again keeps its storage instead of handing back a new `Vec`, so once
+/// it has grown to fit a position no more allocations are made. Capture
+/// chains live inside their moves; only one longer than
+/// `CaptureSequence::INLINE_JUMPS` jumps needs the heap.
+#[derive(Clone, Default, Debug)]
+pub struct MoveList {
+    moves: Vec<Move>,
+    // Captures are gathered apart until it is known whether they rule out
+    // the plain moves
+    captures: Vec<Move>,
+}
 
-    for piece in pieces {
-        moves.extend(all_moves_per_piece(board, &piece));
+impl MoveList {
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    // Check if there are jumps: if yes, only allow jumps
-    let has_jumps = moves.iter().any(|m| m.move_type == MoveType::Jump);
-    if has_jumps {
-        moves.retain(|m| m.move_type == MoveType::Jump);
+    pub fn into_vec(self) -> Vec<Move> {
+        self.moves
     }
+}
 
-    moves
+impl Deref for MoveList {
+    type Target = [Move];
+
+    fn deref(&self) -> &[Move] {
+        &self.moves
+    }
 }
 
-fn all_ways_per_piece(piece: &PieceInstance) -> Vec<MoveDirection> {
-    let mut ways: Vec<MoveDirection> = vec![];
+impl<'a> IntoIterator for &'a MoveList {
+    type Item = &'a Move;
+    type IntoIter = std::slice::Iter<'a, Move>;
 
-    if piece.data.is_king || piece.data.owner == Side::AI {
-        ways.push(MoveDirection {
-            hor: (MoveHorizontal::Left),
-            ver: (MoveVertical::Down),
-        });
-        ways.push(MoveDirection {
-            hor: (MoveHorizontal::Right),
-            ver: (MoveVertical::Down),
-        });
+    fn into_iter(self) -> Self::IntoIter {
+        self.moves.iter()
     }
-    if piece.data.is_king || piece.data.owner == Side::Player {
-        ways.push(MoveDirection {
-            hor: (MoveHorizontal::Left),
-            ver: (MoveVertical::Up),
-        });
-        ways.push(MoveDirection {
-            hor: (MoveHorizontal::Right),
-            ver: (MoveVertical::Up),
-        });
+}
+
+/// Fills `list` with the moves `side` may play on `board`, replacing what it
+/// held before.
+pub fn generate_moves<R: RuleSet>(board: &Board<R>, side: Side, list: &mut MoveList) {
+    let rules = board.rules();
+    list.moves.clear();
+    list.captures.clear();
+
+    let shifts = Shifts::new(board, side);
+    side_captures(board, side, shifts.as_ref(), &mut list.captures);
+    // Check if there are captures: if yes, only allow captures
+    if rules.mandatory_capture() && !list.captures.is_empty() {
+        std::mem::swap(&mut list.moves, &mut list.captures);
+        return;
     }
-    ways
+
+    if let Some(shifts) = &shifts {
+        shifts.plain_moves(shifts.bits.pieces(side), &mut list.moves);
+    } else {
+        for piece in board.pieces_of_side(side) {
+            plain_moves(board, &piece, &mut list.moves);
+        }
+    }
+    list.moves.retain(|mv| rules.allows_move(board, mv));
+
+    if rules.huffing() && !list.captures.is_empty() {
+        huff_plain_moves(&mut list.moves, &list.captures);
+    }
+    list.moves.append(&mut list.captures);
 }
 
-fn all_moves_per_piece(board: &Board, piece: &PieceInstance) -> Vec<Move> {
-    let ways = all_ways_per_piece(piece);
+// The capture chains `side` may choose from, added to `captures`. Which ones
+// those are can depend on the chains of every piece, e.g. when only the
+// longest on the board may be played.
+fn side_captures<R: RuleSet>(
+    board: &Board<R>,
+    side: Side,
+    shifts: Option<&Shifts>,
+    captures: &mut Vec<Move>,
+) {
+    let rules = board.rules();
+    if let Some(shifts) = shifts {
+        shifts.captures(board, shifts.bits.pieces(side), captures);
+    } else {
+        for piece in board.pieces_of_side(side) {
+            piece_captures(board, &piece, captures);
+        }
+    }
+    captures.retain(|mv| rules.allows_move(board, mv));
 
-    let mut moves: Vec<Move> = vec![];
+    if !captures.is_empty() {
+        *captures = rules.restrict_captures(board, std::mem::take(captures));
+    }
+}
+
+pub fn moves_per_side<R: RuleSet>(board: &Board<R>, side: Side) -> Vec<Move> {
+    let mut list = MoveList::new();
+    generate_moves(board, side, &mut list);
+    list.into_vec()
+}
 
-    for direction in ways {
-        if let Some(correct_move) = check_move(board, piece, direction) {
-            moves.push(correct_move);
+/// Whether `side` has a legal move on `board`, without listing them.
+pub fn has_any_move<R: RuleSet>(board: &Board<R>, side: Side) -> bool {
+    board
+        .pieces_of_side(side)
+        .any(|piece| has_plain_move(board, &piece))
+        || has_capture(board, side)
+}
+
+/// Whether `side` can capture on `board`, without listing the captures. It
+/// stops at the first jump the rules allow to start a chain with.
+pub fn has_capture<R: RuleSet>(board: &Board<R>, side: Side) -> bool {
+    if let Some(shifts) = Shifts::new(board, side) {
+        return shifts.has_jump(board);
+    }
+
+    let rules = board.rules();
+    board.pieces_of_side(side).any(|piece| {
+        rules
+            .capture_directions(&piece.data)
+            .iter()
+            .any(|direction| {
+                find_capture(board, &piece, piece.position, direction, &[]).is_some_and(|taken| {
+                    landings(board, &piece, taken, direction)
+                        .take_while(|to| is_vacant(board, piece.position, &[], to))
+                        .any(|to| {
+                            rules.allows_move(
+                                board,
+                                &Move {
+                                    from: piece.position,
+                                    to,
+                                    move_type: MoveType::Jump,
+                                },
+                            )
+                        })
+                })
+            })
+    })
+}
+
+fn has_plain_move<R: RuleSet>(board: &Board<R>, piece: &PieceInstance) -> bool {
+    let rules = board.rules();
+    rules.move_directions(&piece.data).iter().any(|direction| {
+        squares_along(board, piece.position, *direction)
+            .next()
+            .is_some_and(|to| {
+                board.get_square(&to).is_none()
+                    && rules.allows_move(
+                        board,
+                        &Move {
+                            from: piece.position,
+                            to,
+                            move_type: MoveType::Move,
+                        },
+                    )
+            })
+    })
+}
+
+// Turns every plain move into one that forfeits a piece that could have
+// captured. The moving piece goes, from its new square, if it could have
+// captured itself; otherwise the one with the longest capture goes, the
+// first of them row by row on a tie.
+fn huff_plain_moves(moves: &mut [Move], captures: &[Move]) {
+    let Some(longest) = captures
+        .iter()
+        .rev()
+        .max_by_key(|capture| capture.captured().len())
+    else {
+        return;
+    };
+
+    for mv in moves {
+        let huffed = if captures.iter().any(|capture| capture.from == mv.from) {
+            mv.to
+        } else {
+            longest.from
+        };
+        mv.move_type = MoveType::Huffed(huffed);
+    }
+}
+
+// Plain moves of `piece`, added to `moves` whether or not the rules allow
+// them this turn.
+fn plain_moves<R: RuleSet>(board: &Board<R>, piece: &PieceInstance, moves: &mut Vec<Move>) {
+    if let Some(shifts) = Shifts::new(board, piece.data.owner) {
+        shifts.plain_moves(BitBoard::bit(&piece.position).unwrap_or(0), moves);
+        return;
+    }
+
+    let rules = board.rules();
+    let flying = piece.data.is_king && rules.flying_kings();
+    for direction in rules.move_directions(&piece.data) {
+        for to in squares_along(board, piece.position, *direction) {
+            if board.get_square(&to).is_some() {
+                break;
+            }
+            moves.push(Move {
+                from: piece.position,
+                to,
+                move_type: MoveType::Move,
+            });
+            if !flying {
+                break;
+            }
         }
     }
-    moves
 }
 
-pub fn moves_per_piece(board: &Board, piece: &PieceInstance) -> Vec<Move> {
-    let all_side_moves = moves_per_side(board, piece.data.owner);
-    all_side_moves
-        .into_iter()
-        .filter(|m| m.from == piece.position)
-        .collect()
+// Every capture chain `piece` can make, added to `chains`.
+fn piece_captures<R: RuleSet>(board: &Board<R>, piece: &PieceInstance, chains: &mut Vec<Move>) {
+    if let Some(shifts) = Shifts::new(board, piece.data.owner) {
+        shifts.captures(board, BitBoard::bit(&piece.position).unwrap_or(0), chains);
+        return;
+    }
+
+    collect_captures(
+        board,
+        piece,
+        piece.position,
+        None,
+        &mut CaptureSequence::default(),
+        chains,
+    );
 }
 
-pub fn check_move(board: &Board, piece: &PieceInstance, move_to: MoveDirection) -> Option<Move> {
-    //correct dirrection?
-    if !piece.data.is_king
-        && match move_to.ver {
-            MoveVertical::Up => piece.data.owner != Side::Player,
-            MoveVertical::Down => piece.data.owner != Side::AI,
+// Index of a man's and of a king's entry in the tables of `Shifts`
+const MAN: usize = 0;
+const KING: usize = 1;
+
+// Move generation for one side on the bit masks of an 8x8 board, used when
+// its pieces only ever step to the next square along a diagonal. The
+// directions of each kind of piece are looked up once, and a move in one
+// direction is found for every piece of a kind with a single shift.
+struct Shifts<'a> {
+    bits: &'a BitBoard,
+    side: Side,
+    moves: [&'static [MoveDirection]; 2],
+    captures: [&'static [MoveDirection]; 2],
+}
+
+impl<'a> Shifts<'a> {
+    fn new<R: RuleSet>(board: &'a Board<R>, side: Side) -> Option<Self> {
+        let bits = board.bits()?;
+        let rules = board.rules();
+        if rules.flying_kings() || rules.removes_pieces_mid_capture() {
+            return None;
         }
+
+        let man = PieceData {
+            owner: side,
+            is_king: false,
+        };
+        let king = PieceData {
+            owner: side,
+            is_king: true,
+        };
+        let shifts = Shifts {
+            bits,
+            side,
+            moves: [rules.move_directions(&man), rules.move_directions(&king)],
+            captures: [
+                rules.capture_directions(&man),
+                rules.capture_directions(&king),
+            ],
+        };
+
+        let diagonal = shifts
+            .moves
+            .iter()
+            .chain(&shifts.captures)
+            .flat_map(|directions| directions.iter())
+            .all(|direction| BitBoard::shift(0, direction).is_some());
+        diagonal.then_some(shifts)
+    }
+
+    // The men or the kings of the side among `from`
+    fn pieces(&self, kind: usize, from: u32) -> u32 {
+        let kings = if kind == KING {
+            self.bits.kings
+        } else {
+            !self.bits.kings
+        };
+        self.bits.pieces(self.side) & kings & from
+    }
+
+    fn shift(mask: u32, direction: &MoveDirection) -> u32 {
+        BitBoard::shift(mask, direction).unwrap_or(0)
+    }
+
+    // Plain moves of the pieces on the squares in `from`
+    fn plain_moves(&self, from: u32, moves: &mut Vec<Move>) {
+        let empty = self.bits.empty();
+        for kind in [MAN, KING] {
+            let pieces = self.pieces(kind, from);
+            if pieces == 0 {
+                continue;
+            }
+            for direction in self.moves[kind] {
+                let back = direction.opposite();
+                for to in BitBoard::squares(Self::shift(pieces, direction) & empty) {
+                    moves.push(Move {
+                        from: BitBoard::position(Self::shift(to, &back)),
+                        to: BitBoard::position(to),
+                        move_type: MoveType::Move,
+                    });
+                }
+            }
+        }
+    }
+
+    // The pieces among `from` with an enemy next to them and an empty square
+    // behind it. The rules may still forbid the jump, but the other pieces
+    // have no capture at all.
+    fn jumpers(&self, from: u32) -> u32 {
+        let enemies = self.bits.pieces(self.side.opposite());
+        let empty = self.bits.empty();
+        let mut jumpers = 0;
+        for kind in [MAN, KING] {
+            let pieces = self.pieces(kind, from);
+            if pieces == 0 {
+                continue;
+            }
+            for direction in self.captures[kind] {
+                let victims = Self::shift(pieces, direction) & enemies;
+                let landings = Self::shift(victims, direction) & empty;
+                let back = direction.opposite();
+                jumpers |= Self::shift(Self::shift(landings, &back), &back);
+            }
+        }
+        jumpers
+    }
+
+    // Whether any piece of the side has a first jump the rules allow
+    fn has_jump<R: RuleSet>(&self, board: &Board<R>) -> bool {
+        let rules = board.rules();
+        let enemies = self.bits.pieces(self.side.opposite());
+        let empty = self.bits.empty();
+        for kind in [MAN, KING] {
+            let pieces = self.pieces(kind, !0);
+            let piece = PieceData {
+                owner: self.side,
+                is_king: kind == KING,
+            };
+            for direction in self.captures[kind] {
+                let victims = Self::shift(pieces, direction) & enemies;
+                let back = direction.opposite();
+                for landing in BitBoard::squares(Self::shift(victims, direction) & empty) {
+                    let victim = Self::shift(landing, &back);
+                    let jump = Move {
+                        from: BitBoard::position(Self::shift(victim, &back)),
+                        to: BitBoard::position(landing),
+                        move_type: MoveType::Jump,
+                    };
+                    if self
+                        .bits
+                        .get(&BitBoard::position(victim))
+                        .is_some_and(|victim| rules.can_capture(&piece, &victim))
+                        && rules.allows_move(board, &jump)
+                    {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    // Every capture chain of the pieces on the squares in `from`
+    fn captures<R: RuleSet>(&self, board: &Board<R>, from: u32, chains: &mut Vec<Move>) {
+        let mut chain = CaptureSequence::default();
+        for origin in BitBoard::squares(self.jumpers(from)) {
+            let Some(piece) = self.bits.get(&BitBoard::position(origin)) else {
+                continue;
+            };
+            self.chains(board.rules(), &piece, origin, origin, &mut chain, chains);
+        }
+    }
+
+    // `collect_captures` on the bit masks: `at` is the square the piece has
+    // got to, `origin` the one it left, which counts as empty until the chain
+    // ends.
+    fn chains<R: RuleSet>(
+        &self,
+        rules: &R,
+        piece: &PieceData,
+        origin: u32,
+        at: u32,
+        chain: &mut CaptureSequence,
+        chains: &mut Vec<Move>,
+    ) {
+        let enemies = self.bits.pieces(self.side.opposite());
+        let empty = self.bits.empty() | origin;
+        let mut extended = false;
+
+        for direction in self.captures[piece.is_king as usize] {
+            let victim = Self::shift(at, direction) & enemies;
+            if victim == 0 {
+                continue;
+            }
+            let landing = Self::shift(victim, direction) & empty;
+            let taken = BitBoard::position(victim);
+            if landing == 0
+                || chain.captured().contains(&taken)
+                || self
+                    .bits
+                    .get(&taken)
+                    .is_none_or(|victim| !rules.can_capture(piece, &victim))
+            {
+                continue;
+            }
+
+            let landing_pos = BitBoard::position(landing);
+            let crowned = !piece.is_king && landing_pos.row == rules.promotion_row(piece.owner);
+            let landed = PieceData {
+                owner: piece.owner,
+                is_king: piece.is_king || (crowned && rules.promotes_mid_capture()),
+            };
+
+            chain.path.push(landing_pos);
+            chain.captured.push(taken);
+            if crowned && rules.crowning_ends_capture() {
+                chains.push(capture_chain(BitBoard::position(origin), chain));
+            } else {
+                self.chains(rules, &landed, origin, landing, chain, chains);
+            }
+            chain.path.pop();
+            chain.captured.pop();
+
+            extended = true;
+        }
+
+        if !extended && !chain.path().is_empty() {
+            chains.push(capture_chain(BitBoard::position(origin), chain));
+        }
+    }
+}
+
+// Follows every jump available to `piece` and records each chain that cannot
+// be extended any further as one capture move starting at `from`. Jumped
+// pieces stay on the board until the chain is over, blocking the way and
+// unable to be jumped twice, unless the rules remove them at once. A flying
+// king that can land on several squares behind a piece has to pick one it
+// can go on capturing from, if there is one.
+fn collect_captures<R: RuleSet>(
+    board: &Board<R>,
+    piece: &PieceInstance,
+    from: Position,
+    came: Option<MoveDirection>,
+    chain: &mut CaptureSequence,
+    chains: &mut Vec<Move>,
+) {
+    let rules = board.rules();
+    let flying = piece.data.is_king && rules.flying_kings();
+    let mut extended = false;
+
+    for direction in jump_directions(rules, &piece.data, came) {
+        let Some(taken) = find_capture(board, piece, from, direction, chain.captured()) else {
+            continue;
+        };
+
+        chain.captured.push(taken);
+        let goes_on = |landing: Position, captured: &[Position]| {
+            let (landed, ends) = land(rules, piece, landing);
+            !ends && can_capture_from(board, &landed, from, *direction, captured)
+        };
+        let must_go_on = flying
+            && landings(board, piece, taken, direction)
+                .take_while(|landing| is_vacant(board, from, chain.captured(), landing))
+                .any(|landing| goes_on(landing, chain.captured()));
+
+        for landing in landings(board, piece, taken, direction) {
+            if !is_vacant(board, from, chain.captured(), &landing) {
+                break;
+            }
+            if must_go_on && !goes_on(landing, chain.captured()) {
+                continue;
+            }
+
+            chain.path.push(landing);
+            match land(rules, piece, landing) {
+                (_, true) => chains.push(capture_chain(from, chain)),
+                (landed, false) => {
+                    collect_captures(board, &landed, from, Some(*direction), chain, chains)
+                }
+            }
+            chain.path.pop();
+
+            extended = true;
+        }
+        chain.captured.pop();
+    }
+
+    if !extended && !chain.path().is_empty() {
+        chains.push(capture_chain(from, chain));
+    }
+}
+
+// Directions `piece` may take its next jump in, having `came` along the last
+// one. Where jumped pieces leave the board at once, turning straight back is
+// not allowed.
+fn jump_directions<R: RuleSet>(
+    rules: &R,
+    piece: &PieceData,
+    came: Option<MoveDirection>,
+) -> impl Iterator<Item = &'static MoveDirection> {
+    let back = came
+        .filter(|_| rules.removes_pieces_mid_capture())
+        .map(|came| came.opposite());
+    rules
+        .capture_directions(piece)
+        .iter()
+        .filter(move |direction| Some(**direction) != back)
+}
+
+// `piece` after a jump onto `landing`, crowned there if the rules crown it
+// mid-capture, and whether being crowned ends the chain.
+fn land<R: RuleSet>(rules: &R, piece: &PieceInstance, landing: Position) -> (PieceInstance, bool) {
+    let crowned = !piece.data.is_king && landing.row == rules.promotion_row(piece.data.owner);
+    let landed = PieceInstance {
+        position: landing,
+        data: PieceData {
+            owner: piece.data.owner,
+            is_king: piece.data.is_king || (crowned && rules.promotes_mid_capture()),
+        },
+    };
+    (landed, crowned && rules.crowning_ends_capture())
+}
+
+// Whether `piece` has another jump on its way through a chain that started
+// at `from`, came along `came` and has taken `captured` so far.
+fn can_capture_from<R: RuleSet>(
+    board: &Board<R>,
+    piece: &PieceInstance,
+    from: Position,
+    came: MoveDirection,
+    captured: &[Position],
+) -> bool {
+    jump_directions(board.rules(), &piece.data, Some(came))
+        .any(|direction| find_capture(board, piece, from, direction, captured).is_some())
+}
+
+// Looks along `direction` for an enemy piece `piece` can jump with at least
+// one square to land on behind it, and returns its square.
+fn find_capture<R: RuleSet>(
+    board: &Board<R>,
+    piece: &PieceInstance,
+    from: Position,
+    direction: &MoveDirection,
+    captured: &[Position],
+) -> Option<Position> {
+    let flying = piece.data.is_king && board.rules().flying_kings();
+
+    let mut squares = squares_along(board, piece.position, *direction);
+    let taken = loop {
+        let pos = squares.next()?;
+        if !is_vacant(board, from, captured, &pos) {
+            break pos;
+        }
+        if !flying {
+            return None;
+        }
+    };
+
+    let victim = board.get_square(&taken)?;
+    if victim.owner == piece.data.owner
+        || !board.rules().can_capture(&piece.data, victim)
+        || captured.contains(&taken)
     {
         return None;
     }
 
+    squares
+        .next()
+        .filter(|landing| is_vacant(board, from, captured, landing))?;
+    Some(taken)
+}
+
+// The squares behind `taken` that `piece` could land on, nearest first, as
+// far as the board goes. Only those up to the first one that is not vacant
+// can be used.
+fn landings<'a, R: RuleSet>(
+    board: &'a Board<R>,
+    piece: &PieceInstance,
+    taken: Position,
+    direction: &MoveDirection,
+) -> impl Iterator<Item = Position> + use<'a, R> {
+    let reach = if piece.data.is_king && board.rules().flying_kings() {
+        usize::MAX
+    } else {
+        1
+    };
+    squares_along(board, taken, *direction).take(reach)
+}
+
+// Whether a capture chain that started at `from` and has taken `captured`
+// so far may pass over or land on `pos`. The capturing piece has left
+// `from`, and pieces the rules remove mid-capture are gone.
+fn is_vacant<R: RuleSet>(
+    board: &Board<R>,
+    from: Position,
+    captured: &[Position],
+    pos: &Position,
+) -> bool {
+    *pos == from
+        || board.get_square(pos).is_none()
+        || (board.rules().removes_pieces_mid_capture() && captured.contains(pos))
+}
+
+// Playable squares met walking from `start` along `direction`, nearest first.
+// Squares pieces cannot stand on are stepped over, so an orthogonal line on a
+// diagonal board reaches every other square.
+fn squares_along<R: RuleSet>(
+    board: &Board<R>,
+    start: Position,
+    direction: MoveDirection,
+) -> impl Iterator<Item = Position> + '_ {
+    (1..)
+        .map_while(move |steps| start.setup_moved(&direction, steps, board.size()).ok())
+        .filter(|pos| board.rules().is_playable(pos))
+}
+
+fn capture_chain(from: Position, chain: &CaptureSequence) -> Move {
+    let path = chain.path();
+    Move {
+        from,
+        to: path[path.len() - 1],
+        move_type: MoveType::Capture(chain.clone()),
+    }
+}
+
+pub fn moves_per_piece<R: RuleSet>(board: &Board<R>, piece: &PieceInstance) -> Vec<Move> {
+    let rules = board.rules();
+    let side = piece.data.owner;
+    let capturing = has_capture(board, side);
+
+    let mut moves = vec![];
+    if !capturing || !rules.mandatory_capture() {
+        plain_moves(board, piece, &mut moves);
+        moves.retain(|mv| rules.allows_move(board, mv));
+    }
+    if !capturing {
+        return moves;
+    }
+
+    let mut captures = vec![];
+    piece_captures(board, piece, &mut captures);
+    captures.retain(|mv| rules.allows_move(board, mv));
+
+    // Whether this piece's chains may be played can depend on the chains of
+    // the others, and which piece a huff forfeits depends on every capture,
+    // so only then are the whole side's captures needed
+    let huffing = rules.huffing() && !rules.mandatory_capture();
+    if !captures.is_empty() || huffing {
+        let mut all = vec![];
+        side_captures(board, side, Shifts::new(board, side).as_ref(), &mut all);
+        if huffing {
+            huff_plain_moves(&mut moves, &all);
+        }
+        captures = all
+            .into_iter()
+            .filter(|mv| mv.from == piece.position)
+            .collect();
+    }
+
+    moves.append(&mut captures);
+    moves
+}
+
+pub fn check_move<R: RuleSet>(
+    board: &Board<R>,
+    piece: &PieceInstance,
+    move_to: MoveDirection,
+) -> Option<Move> {
+    let rules = board.rules();
+    let can_move = rules.move_directions(&piece.data).contains(&move_to);
+    let can_capture = rules.capture_directions(&piece.data).contains(&move_to);
+
+    //correct dirrection?
+    if !can_move && !can_capture {
+        return None;
+    }
+
     //correct move_to_position?
-    match piece.position.setup_moved(&move_to, 1) {
+    match piece.position.setup_moved(&move_to, 1, board.size()) {
         Ok(moved_pos) => {
             //is there alive piece?
             match board.get_square(&moved_pos) {
                 Some(moved_pos_piece) => {
-                    //is it in black list?
-
                     //mine?
-                    if moved_pos_piece.owner == piece.data.owner {
-                        return None;
+                    if moved_pos_piece.owner == piece.data.owner
+                        || !can_capture
+                        || !rules.can_capture(&piece.data, moved_pos_piece)
+                    {
+                        None
                     } else {
                         //is there place behind it?
-                        match piece.position.setup_moved(&move_to, 2) {
+                        match piece.position.setup_moved(&move_to, 2, board.size()) {
                             Ok(jump_pos) => {
                                 //is there piece?
                                 match board.get_square(&jump_pos) {
-                                    Some(_) => return None,
+                                    Some(_) => None,
                                     None => {
                                         //make_jump
-                                        return Some(Move {
+                                        Some(Move {
                                             from: piece.position,
                                             to: jump_pos,
                                             move_type: MoveType::Jump,
-                                        });
+                                        })
                                     }
                                 }
                             }
-                            Err(_) => return None,
+                            Err(_) => None,
                         }
                     }
                 }
-                None => {
-                    return Some(Move {
-                        from: piece.position,
-                        to: moved_pos,
-                        move_type: MoveType::Move,
-                    });
-                }
+                None if can_move => Some(Move {
+                    from: piece.position,
+                    to: moved_pos,
+                    move_type: MoveType::Move,
+                }),
+                None => None,
             }
         }
-        Err(_) => return None,
-    };
+        Err(_) => None,
+    }
 }
@@ -0,0 +1,42 @@
+use super::{RuleSet, keep_majority_captures};
+use crate::board::Board;
+use crate::board::movement::Move;
+use crate::board::movement::direction::MoveDirection;
+use crate::board::piece::PieceData;
+
+/// Canadian checkers: International draughts played on a 12x12 board with
+/// 30 men a side.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct CanadianCheckers;
+
+impl RuleSet for CanadianCheckers {
+    type State = ();
+
+    fn board_size(&self) -> usize {
+        12
+    }
+
+    fn starting_rows(&self) -> usize {
+        5
+    }
+
+    fn crowning_ends_capture(&self) -> bool {
+        false
+    }
+
+    fn capture_directions(&self, _piece: &PieceData) -> &'static [MoveDirection] {
+        MoveDirection::all_diagonals()
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn promotes_mid_capture(&self) -> bool {
+        false
+    }
+
+    fn restrict_captures(&self, _board: &Board<Self>, captures: Vec<Move>) -> Vec<Move> {
+        keep_majority_captures(captures)
+    }
+}
@@ -0,0 +1,21 @@
+use super::RuleSet;
+
+/// English draughts for beginners: capturing is optional. With `huffing` on,
+/// a side that makes a plain move instead of capturing loses the piece that
+/// could have captured.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct CasualDraughts {
+    pub huffing: bool,
+}
+
+impl RuleSet for CasualDraughts {
+    type State = ();
+
+    fn mandatory_capture(&self) -> bool {
+        false
+    }
+
+    fn huffing(&self) -> bool {
+        self.huffing
+    }
+}
@@ -0,0 +1,11 @@
+use super::RuleSet;
+
+/// English draughts (American checkers): 8x8, men move and capture forward
+/// only, kings move one square, capturing is mandatory and crowning ends
+/// the move.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct EnglishDraughts;
+
+impl RuleSet for EnglishDraughts {
+    type State = ();
+}
@@ -0,0 +1,119 @@
+use super::RuleSet;
+use crate::board::Board;
+use crate::board::movement::Move;
+use crate::board::movement::direction::MoveDirection;
+use crate::board::piece::PieceData;
+use crate::board::piece::side::Side;
+
+/// Plain king moves a side may make in a row while it still has men.
+const KING_MOVES_IN_A_ROW: u8 = 3;
+
+/// Frisian draughts: 10x10 with 20 men a side. Every piece captures along
+/// diagonals as well as rows and columns, jumping to the next square of its
+/// own colour, and kings fly. The capture taking the most value is mandatory,
+/// a king being worth more than a man but less than two.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct FrisianDraughts;
+
+/// Plain king moves each side has made in a row, counted up to the limit.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct FrisianState {
+    pub dark_king_moves: u8,
+    pub light_king_moves: u8,
+}
+
+impl FrisianState {
+    pub fn king_moves(&self, side: Side) -> u8 {
+        match side {
+            Side::Dark => self.dark_king_moves,
+            Side::Light => self.light_king_moves,
+        }
+    }
+
+    fn king_moves_mut(&mut self, side: Side) -> &mut u8 {
+        match side {
+            Side::Dark => &mut self.dark_king_moves,
+            Side::Light => &mut self.light_king_moves,
+        }
+    }
+}
+
+impl FrisianDraughts {
+    fn value(piece: &PieceData) -> usize {
+        if piece.is_king { 3 } else { 2 }
+    }
+}
+
+impl RuleSet for FrisianDraughts {
+    type State = FrisianState;
+
+    fn board_size(&self) -> usize {
+        10
+    }
+
+    fn starting_rows(&self) -> usize {
+        4
+    }
+
+    fn crowning_ends_capture(&self) -> bool {
+        false
+    }
+
+    fn capture_directions(&self, _piece: &PieceData) -> &'static [MoveDirection] {
+        MoveDirection::all_lines()
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn promotes_mid_capture(&self) -> bool {
+        false
+    }
+
+    fn restrict_captures(&self, board: &Board<Self>, mut captures: Vec<Move>) -> Vec<Move> {
+        let priority = |mv: &Move| {
+            let taken: usize = mv
+                .captured()
+                .iter()
+                .filter_map(|pos| board.get_square(pos))
+                .map(Self::value)
+                .sum();
+            let by_king = board.get_square(&mv.from).is_some_and(|p| p.is_king);
+
+            (taken, by_king)
+        };
+
+        if let Some(best) = captures.iter().map(priority).max() {
+            captures.retain(|mv| priority(mv) == best);
+        }
+        captures
+    }
+
+    fn allows_move(&self, board: &Board<Self>, mv: &Move) -> bool {
+        let Some(piece) = board.get_square(&mv.from) else {
+            return false;
+        };
+        if !piece.is_king || mv.is_capture() {
+            return true;
+        }
+
+        let has_men = board.pieces_of_side(piece.owner).any(|p| !p.data.is_king);
+        !has_men || board.state().king_moves(piece.owner) < KING_MOVES_IN_A_ROW
+    }
+
+    fn record_move(&self, state: &mut FrisianState, board: &Board<Self>, mv: &Move) {
+        let Some(piece) = board.get_square(&mv.from) else {
+            return;
+        };
+
+        let king_moves = state.king_moves_mut(piece.owner);
+        if piece.is_king && !mv.is_capture() {
+            // Past the limit the count makes no difference, and stopping
+            // there lets positions that only differ beyond it repeat
+            *king_moves = (*king_moves + 1).min(KING_MOVES_IN_A_ROW);
+        } else {
+            *king_moves = 0;
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use super::RuleSet;
+
+/// Giveaway (losing) draughts: English rules with the goal reversed. Capture
+/// is still mandatory, and the first side to lose all its pieces or be left
+/// without a move wins.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct GiveawayDraughts;
+
+impl RuleSet for GiveawayDraughts {
+    type State = ();
+
+    fn losing_game(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,43 @@
+use super::{RuleSet, keep_majority_captures};
+use crate::board::Board;
+use crate::board::movement::Move;
+use crate::board::movement::direction::MoveDirection;
+use crate::board::piece::PieceData;
+
+/// International (Polish) draughts: 10x10 with 20 men a side, men capture
+/// backwards, kings fly and the capture taking the most pieces is mandatory.
+/// A man only promotes if its move ends on the last row.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct InternationalDraughts;
+
+impl RuleSet for InternationalDraughts {
+    type State = ();
+
+    fn board_size(&self) -> usize {
+        10
+    }
+
+    fn starting_rows(&self) -> usize {
+        4
+    }
+
+    fn crowning_ends_capture(&self) -> bool {
+        false
+    }
+
+    fn capture_directions(&self, _piece: &PieceData) -> &'static [MoveDirection] {
+        MoveDirection::all_diagonals()
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn promotes_mid_capture(&self) -> bool {
+        false
+    }
+
+    fn restrict_captures(&self, _board: &Board<Self>, captures: Vec<Move>) -> Vec<Move> {
+        keep_majority_captures(captures)
+    }
+}
@@ -0,0 +1,47 @@
+use std::cmp::Reverse;
+
+use super::RuleSet;
+use crate::board::Board;
+use crate::board::movement::Move;
+use crate::board::piece::PieceData;
+use crate::board::position::Position;
+
+/// Italian draughts: English movement on a board turned so the bottom-left
+/// corner is light. Men may not capture kings, and among the captures on
+/// offer the player must take the most pieces, then capture with a king,
+/// then take the most kings, then take a king as early as possible.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct ItalianDraughts;
+
+impl RuleSet for ItalianDraughts {
+    type State = ();
+
+    fn is_playable(&self, pos: &Position) -> bool {
+        (pos.row + pos.col).is_multiple_of(2)
+    }
+
+    fn can_capture(&self, attacker: &PieceData, victim: &PieceData) -> bool {
+        attacker.is_king || !victim.is_king
+    }
+
+    fn restrict_captures(&self, board: &Board<Self>, mut captures: Vec<Move>) -> Vec<Move> {
+        let priority = |mv: &Move| {
+            let by_king = board.get_square(&mv.from).is_some_and(|p| p.is_king);
+            let is_king = |pos: &Position| board.get_square(pos).is_some_and(|p| p.is_king);
+            let kings = mv.captured().iter().filter(|pos| is_king(pos)).count();
+            let first_king = mv.captured().iter().position(is_king);
+
+            (
+                mv.captured().len(),
+                by_king,
+                kings,
+                Reverse(first_king.unwrap_or(usize::MAX)),
+            )
+        };
+
+        if let Some(best) = captures.iter().map(priority).max() {
+            captures.retain(|mv| priority(mv) == best);
+        }
+        captures
+    }
+}
@@ -0,0 +1,167 @@
+mod canadian;
+mod casual;
+mod english;
+mod frisian;
+mod giveaway;
+mod international;
+mod italian;
+mod russian;
+mod turkish;
+
+pub use canadian::CanadianCheckers;
+pub use casual::CasualDraughts;
+pub use english::EnglishDraughts;
+pub use frisian::{FrisianDraughts, FrisianState};
+pub use giveaway::GiveawayDraughts;
+pub use international::InternationalDraughts;
+pub use italian::ItalianDraughts;
+pub use russian::RussianDraughts;
+pub use turkish::TurkishDraughts;
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Range;
+
+use crate::board::Board;
+use crate::board::movement::Move;
+use crate::board::movement::direction::{MoveDirection, MoveVertical};
+use crate::board::piece::PieceData;
+use crate::board::piece::side::Side;
+use crate::board::position::Position;
+
+/// Everything that differs between checkers variants. `Board`, `GameManager`
+/// and the AI are generic over it; `EnglishDraughts` is the default.
+///
+/// Provided methods follow English draughts, so a variant only overrides
+/// what it changes.
+pub trait RuleSet: Copy + PartialEq + Debug {
+    /// What the variant tracks about a game besides the pieces, e.g. move
+    /// counters. It travels with the `Board` and is part of its Zobrist
+    /// hash, so states that allow the same moves should compare equal.
+    type State: Copy + Default + PartialEq + Hash + Debug;
+
+    /// Number of rows and columns on the board.
+    fn board_size(&self) -> usize {
+        8
+    }
+
+    /// Rows filled with men on each side at the start of the game.
+    fn starting_rows(&self) -> usize {
+        3
+    }
+
+    /// Directions `piece` may make a plain move in. Men go diagonally
+    /// forward, kings diagonally either way.
+    fn move_directions(&self, piece: &PieceData) -> &'static [MoveDirection] {
+        if piece.is_king {
+            MoveDirection::all_diagonals()
+        } else {
+            MoveDirection::diagonals(self.forward(piece.owner))
+        }
+    }
+
+    /// A side that can capture is not allowed to make a plain move.
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    /// A man crowned in the middle of a capture stops there. Otherwise it
+    /// carries on capturing.
+    fn crowning_ends_capture(&self) -> bool {
+        true
+    }
+
+    /// Whether pieces may stand on `pos`. Play is on the dark squares, with
+    /// a dark square in the bottom-left corner.
+    fn is_playable(&self, pos: &Position) -> bool {
+        (pos.row + pos.col) % 2 == 1
+    }
+
+    /// Directions `piece` may capture in.
+    fn capture_directions(&self, piece: &PieceData) -> &'static [MoveDirection] {
+        self.move_directions(piece)
+    }
+
+    /// Whether `attacker` is allowed to jump `victim`, an enemy piece.
+    fn can_capture(&self, _attacker: &PieceData, _victim: &PieceData) -> bool {
+        true
+    }
+
+    /// When capturing is optional, a side that passes up a capture forfeits
+    /// a piece that could have made it, as `MoveType::Huffed` describes.
+    fn huffing(&self) -> bool {
+        false
+    }
+
+    /// Kings move and capture along whole lines instead of one square.
+    fn flying_kings(&self) -> bool {
+        false
+    }
+
+    /// A man that reaches the promotion row mid-capture is crowned there and
+    /// continues as a king. Otherwise only the final square counts.
+    fn promotes_mid_capture(&self) -> bool {
+        true
+    }
+
+    /// Every piece a capture jumps comes off the board at once, so a chain
+    /// may cross its square again, but may not turn straight back the way
+    /// it came. Otherwise jumped pieces stay until the chain is over and
+    /// block the way.
+    fn removes_pieces_mid_capture(&self) -> bool {
+        false
+    }
+
+    /// The goal is reversed: a side left without pieces or moves wins.
+    fn losing_game(&self) -> bool {
+        false
+    }
+
+    /// Narrows the complete capture chains of one side down to the ones the
+    /// player may choose from, e.g. only those taking the most pieces.
+    fn restrict_captures(&self, _board: &Board<Self>, captures: Vec<Move>) -> Vec<Move> {
+        captures
+    }
+
+    /// Whether the variant state lets `mv` be played on `board`.
+    fn allows_move(&self, _board: &Board<Self>, _mv: &Move) -> bool {
+        true
+    }
+
+    /// Updates `state` for `mv`, which is about to be played on `board`.
+    fn record_move(&self, _state: &mut Self::State, _board: &Board<Self>, _mv: &Move) {}
+
+    /// Direction the men of `side` advance in.
+    fn forward(&self, side: Side) -> MoveVertical {
+        match side {
+            Side::Dark => MoveVertical::Up,
+            Side::Light => MoveVertical::Down,
+        }
+    }
+
+    /// Rows the men of `side` are placed on at the start of the game.
+    fn home_rows(&self, side: Side) -> Range<usize> {
+        let size = self.board_size();
+        match self.forward(side) {
+            MoveVertical::Down => 0..self.starting_rows(),
+            _ => size - self.starting_rows()..size,
+        }
+    }
+
+    /// Row on which the men of `side` are crowned.
+    fn promotion_row(&self, side: Side) -> usize {
+        match self.forward(side) {
+            MoveVertical::Down => self.board_size() - 1,
+            _ => 0,
+        }
+    }
+}
+
+/// Keeps only the captures taking the largest number of pieces.
+pub fn keep_majority_captures(mut captures: Vec<Move>) -> Vec<Move> {
+    let most = captures.iter().map(|m| m.captured().len()).max();
+    if let Some(most) = most {
+        captures.retain(|m| m.captured().len() == most);
+    }
+    captures
+}
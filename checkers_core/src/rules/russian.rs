@@ -0,0 +1,25 @@
+use super::RuleSet;
+use crate::board::movement::direction::MoveDirection;
+use crate::board::piece::PieceData;
+
+/// Russian draughts (shashki): 8x8, men capture backwards and kings fly. A
+/// man reaching the last row mid-capture is crowned and goes on capturing as
+/// a king. Any capture may be chosen, not only the longest.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct RussianDraughts;
+
+impl RuleSet for RussianDraughts {
+    type State = ();
+
+    fn crowning_ends_capture(&self) -> bool {
+        false
+    }
+
+    fn capture_directions(&self, _piece: &PieceData) -> &'static [MoveDirection] {
+        MoveDirection::all_diagonals()
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,67 @@
+use std::ops::Range;
+
+use super::{RuleSet, keep_majority_captures};
+use crate::board::Board;
+use crate::board::movement::Move;
+use crate::board::movement::direction::MoveDirection;
+use crate::board::piece::PieceData;
+use crate::board::piece::side::Side;
+use crate::board::position::Position;
+
+/// Turkish draughts: every square is used and 16 men a side start on the
+/// second and third rows. Men move and capture forward or sideways, kings
+/// fly along rows and columns, and the largest capture is mandatory. Jumped
+/// pieces come off the board one by one, and a capture may not turn back.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct TurkishDraughts;
+
+impl RuleSet for TurkishDraughts {
+    type State = ();
+
+    fn starting_rows(&self) -> usize {
+        2
+    }
+
+    fn move_directions(&self, piece: &PieceData) -> &'static [MoveDirection] {
+        if piece.is_king {
+            MoveDirection::all_orthogonals()
+        } else {
+            MoveDirection::straight_and_sideways(self.forward(piece.owner))
+        }
+    }
+
+    fn crowning_ends_capture(&self) -> bool {
+        false
+    }
+
+    fn is_playable(&self, _pos: &Position) -> bool {
+        true
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn promotes_mid_capture(&self) -> bool {
+        false
+    }
+
+    fn removes_pieces_mid_capture(&self) -> bool {
+        true
+    }
+
+    fn restrict_captures(&self, _board: &Board<Self>, captures: Vec<Move>) -> Vec<Move> {
+        keep_majority_captures(captures)
+    }
+
+    fn home_rows(&self, side: Side) -> Range<usize> {
+        // The back row is left empty
+        let rows = self.starting_rows();
+        let last = self.board_size() - 1;
+        if self.promotion_row(side) == last {
+            1..1 + rows
+        } else {
+            last - rows..last
+        }
+    }
+}
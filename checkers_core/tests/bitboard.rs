@@ -0,0 +1,153 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{piece, pos};
+
+fn up_left() -> MoveDirection {
+    MoveDirection {
+        hor: MoveHorizontal::Left,
+        ver: MoveVertical::Up,
+    }
+}
+
+fn down_right() -> MoveDirection {
+    MoveDirection {
+        hor: MoveHorizontal::Right,
+        ver: MoveVertical::Down,
+    }
+}
+
+#[test]
+fn english_board_is_kept_as_bits() {
+    let board = Board::setup();
+    let bits = board.bits().unwrap();
+
+    assert_eq!(bits.light, 0x0000_0FFF);
+    assert_eq!(bits.dark, 0xFFF0_0000);
+    assert_eq!(bits.kings, 0);
+}
+
+#[test]
+fn other_boards_are_not() {
+    assert!(Board::setup_with(InternationalDraughts).bits().is_none());
+    assert!(Board::setup_with(ItalianDraughts).bits().is_none());
+    assert!(Board::setup_with(TurkishDraughts).bits().is_none());
+}
+
+#[test]
+fn bits_map_to_dark_squares() {
+    for row in 0..8 {
+        for col in 0..8 {
+            let square = pos(row, col);
+            match BitBoard::bit(&square) {
+                Some(bit) => assert_eq!(BitBoard::position(bit), square),
+                None => assert_eq!((row + col) % 2, 0),
+            }
+        }
+    }
+}
+
+#[test]
+fn shifts_follow_the_diagonals() {
+    let bit = |row, col| BitBoard::bit(&pos(row, col)).unwrap();
+
+    assert_eq!(BitBoard::shift(bit(5, 2), &up_left()), Some(bit(4, 1)));
+    assert_eq!(BitBoard::shift(bit(4, 1), &up_left()), Some(bit(3, 0)));
+    assert_eq!(BitBoard::shift(bit(2, 7), &down_right()), Some(0));
+    assert_eq!(BitBoard::shift(bit(3, 0), &up_left()), Some(0));
+    assert_eq!(BitBoard::shift(bit(0, 1), &up_left()), Some(0));
+    assert_eq!(
+        BitBoard::shift(bit(3, 0), &MoveDirection::straight(MoveVertical::Up)),
+        None
+    );
+}
+
+#[test]
+fn squares_and_pieces_read_back_from_the_bits() {
+    let mut board = Board::empty();
+    let king = PieceData {
+        owner: Side::Light,
+        is_king: true,
+    };
+    board.set_square(&pos(6, 3), Some(king));
+
+    assert_eq!(board.get_square(&pos(6, 3)), Some(&king));
+    assert_eq!(board.get_square(&pos(6, 2)), None);
+    let pieces = board.all_pieces_of_side(Side::Light);
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0].position, pos(6, 3));
+    assert!(pieces[0].data.is_king);
+
+    board.set_square(&pos(6, 3), None);
+    assert_eq!(board.bits(), Some(&BitBoard::default()));
+}
+
+#[test]
+fn opening_moves_come_from_the_front_row() {
+    let board = Board::setup();
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 7);
+    assert!(moves.iter().all(|mv| mv.from.row == 5 && mv.to.row == 4));
+}
+
+#[test]
+fn shifted_moves_match_a_square_by_square_walk() {
+    let mut board = Board::setup();
+    let mut side = Side::Dark;
+
+    for _ in 0..30 {
+        let moves = moves_per_side(&board, side);
+        if moves.is_empty() {
+            break;
+        }
+
+        if !moves.iter().any(|mv| mv.is_capture()) {
+            let walked: Vec<Move> = board
+                .all_pieces_of_side(side)
+                .iter()
+                .flat_map(|piece| {
+                    MoveDirection::all_diagonals()
+                        .iter()
+                        .filter_map(|direction| check_move(&board, piece, *direction))
+                })
+                .filter(|mv| mv.move_type == MoveType::Move)
+                .collect();
+            assert_eq!(moves.len(), walked.len());
+            assert!(walked.iter().all(|mv| moves.contains(mv)));
+        }
+
+        let mv = decide_move(&board, side, 2).unwrap();
+        board.apply_move(&mv).unwrap();
+        side = side.opposite();
+    }
+}
+
+#[test]
+fn squares_off_the_board_are_empty() {
+    let mut board = Board::empty_with(InternationalDraughts);
+    board.set_square(&pos(2, 1), piece(Side::Dark, false));
+
+    // Would be (2, 1) if the column ran on into the next row
+    assert_eq!(board.get_square(&pos(1, 11)), None);
+    assert_eq!(board.get_square(&pos(2, 2)), None);
+    assert_eq!(Board::setup().get_square(&pos(7, 9)), None);
+}
+
+#[test]
+#[should_panic(expected = "not a playable square")]
+fn bits_refuse_a_light_square() {
+    Board::empty().set_square(&pos(4, 4), piece(Side::Dark, false));
+}
+
+#[test]
+#[should_panic(expected = "not a playable square")]
+fn grid_refuses_a_light_square() {
+    Board::empty_with(InternationalDraughts).set_square(&pos(4, 4), piece(Side::Dark, false));
+}
+
+#[test]
+#[should_panic(expected = "not a playable square")]
+fn grid_refuses_a_square_off_the_board() {
+    Board::empty_with(InternationalDraughts).set_square(&pos(1, 11), None);
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::pos;
+
+#[test]
+fn setup_has_thirty_men_a_side() {
+    let board = Board::setup_with(CanadianCheckers);
+
+    assert_eq!(board.size(), 12);
+    assert_eq!(board.all_pieces_of_side(Side::Dark).len(), 30);
+    assert_eq!(board.all_pieces_of_side(Side::Light).len(), 30);
+    assert_eq!(moves_per_side(&board, Side::Dark).len(), 11);
+}
+
+#[test]
+fn far_corner_is_in_bounds() {
+    let board = Board::setup_with(CanadianCheckers);
+
+    assert!(board.position(11, 0).is_ok());
+    assert!(board.position(0, 11).is_ok());
+    assert!(board.position(12, 1).is_err());
+}
+
+#[test]
+fn men_crown_on_the_twelfth_row() {
+    let mut board = Board::empty_with(CanadianCheckers);
+    board.set_square(
+        &pos(1, 2),
+        Some(PieceData {
+            owner: Side::Dark,
+            is_king: false,
+        }),
+    );
+    board.set_square(
+        &pos(10, 1),
+        Some(PieceData {
+            owner: Side::Light,
+            is_king: false,
+        }),
+    );
+    let mut game = GameManager::from_board(board, Side::Dark);
+
+    game.make_move(Move {
+        from: pos(1, 2),
+        to: pos(0, 1),
+        move_type: MoveType::Move,
+    })
+    .unwrap();
+    assert!(game.board.get_square(&pos(0, 1)).unwrap().is_king);
+}
+
+#[test]
+fn ai_plays_on_the_largest_board() {
+    let mut game = GameManager::with_rules(CanadianCheckers);
+
+    for _ in 0..4 {
+        let side = game.current_turn;
+        let mv = decide_move(&game.board, side, 2).unwrap();
+        game.make_move(mv).unwrap();
+    }
+    assert!(!game.is_over());
+}
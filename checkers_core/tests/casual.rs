@@ -0,0 +1,115 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{man, pos};
+
+fn game_with(rules: CasualDraughts) -> GameManager<CasualDraughts> {
+    let mut board = Board::empty_with(rules);
+    board.set_square(&pos(5, 2), man(Side::Dark));
+    board.set_square(&pos(7, 6), man(Side::Dark));
+    board.set_square(&pos(4, 3), man(Side::Light));
+    board.set_square(&pos(0, 1), man(Side::Light));
+    GameManager::from_board(board, Side::Dark)
+}
+
+fn plain_move(game: &GameManager<CasualDraughts>, from: Position, to: Position) -> Move {
+    game.get_possible_moves(from)
+        .into_iter()
+        .find(|m| m.to == to && !m.is_capture())
+        .unwrap()
+}
+
+#[test]
+fn capturing_is_optional() {
+    let game = game_with(CasualDraughts::default());
+    let moves = moves_per_side(&game.board, Side::Dark);
+
+    assert!(moves.iter().any(|m| m.is_capture()));
+    assert!(moves.iter().any(|m| m.move_type == MoveType::Move));
+}
+
+#[test]
+fn passing_up_a_capture_is_free_without_huffing() {
+    let mut game = game_with(CasualDraughts::default());
+    let mv = plain_move(&game, pos(7, 6), pos(6, 5));
+
+    game.make_move(mv).unwrap();
+    assert!(game.board.get_square(&pos(5, 2)).is_some());
+    assert!(game.board.get_square(&pos(6, 5)).is_some());
+}
+
+#[test]
+fn piece_that_could_capture_is_huffed() {
+    let mut game = game_with(CasualDraughts { huffing: true });
+    let mv = plain_move(&game, pos(7, 6), pos(6, 5));
+
+    assert_eq!(mv.move_type, MoveType::Huffed(pos(5, 2)));
+    game.make_move(mv).unwrap();
+    assert!(game.board.get_square(&pos(5, 2)).is_none());
+    assert!(game.board.get_square(&pos(6, 5)).is_some());
+}
+
+#[test]
+fn capturing_piece_moving_away_is_huffed() {
+    let mut game = game_with(CasualDraughts { huffing: true });
+    let mv = plain_move(&game, pos(5, 2), pos(4, 1));
+
+    game.make_move(mv).unwrap();
+    assert!(game.board.get_square(&pos(5, 2)).is_none());
+    assert!(game.board.get_square(&pos(4, 1)).is_none());
+}
+
+#[test]
+fn ai_takes_the_capture_rather_than_lose_a_piece() {
+    let mut game = game_with(CasualDraughts { huffing: true });
+    game.current_turn = Side::Light;
+    game.board.set_square(&pos(5, 2), None);
+    game.board.set_square(&pos(5, 4), man(Side::Dark));
+
+    let mv = decide_move(&game.board, Side::Light, 1).unwrap();
+    assert!(mv.is_capture());
+}
+
+#[test]
+fn huff_falls_on_the_longest_capture() {
+    let mut game = game_with(CasualDraughts { huffing: true });
+    // (5, 4) can take two pieces, (5, 2) only one
+    game.board.set_square(&pos(5, 4), man(Side::Dark));
+    game.board.set_square(&pos(4, 5), man(Side::Light));
+    game.board.set_square(&pos(2, 5), man(Side::Light));
+    game.board.set_square(&pos(1, 6), man(Side::Dark));
+
+    let huffs: Vec<Move> = game
+        .get_possible_moves(pos(7, 6))
+        .into_iter()
+        .filter(|m| m.to == pos(6, 5))
+        .collect();
+    assert_eq!(
+        huffs,
+        [Move {
+            from: pos(7, 6),
+            to: pos(6, 5),
+            move_type: MoveType::Huffed(pos(5, 4)),
+        }]
+    );
+    game.make_move(huffs[0].clone()).unwrap();
+    assert!(game.board.get_square(&pos(5, 2)).is_some());
+    assert!(game.board.get_square(&pos(5, 4)).is_none());
+}
+
+#[test]
+fn huff_falls_on_the_first_piece_on_a_tie() {
+    let mut game = game_with(CasualDraughts { huffing: true });
+    game.board.set_square(&pos(5, 4), man(Side::Dark));
+
+    let moves = moves_per_side(&game.board, Side::Dark);
+    let plain = moves.iter().filter(|m| !m.is_capture());
+    for mv in plain {
+        let expected = if mv.from == pos(5, 2) || mv.from == pos(5, 4) {
+            mv.to
+        } else {
+            pos(5, 2)
+        };
+        assert_eq!(mv.move_type, MoveType::Huffed(expected));
+    }
+}
@@ -0,0 +1,47 @@
+//! Fixtures shared by the integration tests. Each test crate uses only some
+//! of them.
+#![allow(dead_code)]
+
+use checkers_core::prelude::*;
+
+pub fn pos(row: usize, col: usize) -> Position {
+    Position { row, col }
+}
+
+pub fn piece(owner: Side, is_king: bool) -> Option<PieceData> {
+    Some(PieceData { owner, is_king })
+}
+
+pub fn man(owner: Side) -> Option<PieceData> {
+    piece(owner, false)
+}
+
+pub fn king(owner: Side) -> Option<PieceData> {
+    piece(owner, true)
+}
+
+pub fn step(from: Position, to: Position) -> Move {
+    Move {
+        from,
+        to,
+        move_type: MoveType::Move,
+    }
+}
+
+pub fn jump(from: Position, to: Position) -> Move {
+    Move {
+        from,
+        to,
+        move_type: MoveType::Jump,
+    }
+}
+
+/// An empty board under `rules` with `pieces` placed on it, each given as
+/// its square, owner and whether it is a king.
+pub fn board_with<R: RuleSet>(rules: R, pieces: &[(Position, Side, bool)]) -> Board<R> {
+    let mut board = Board::empty_with(rules);
+    for (position, owner, is_king) in pieces {
+        board.set_square(position, piece(*owner, *is_king));
+    }
+    board
+}
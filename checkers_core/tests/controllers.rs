@@ -0,0 +1,53 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::pos;
+
+#[test]
+fn dark_starts_at_the_bottom_and_moves_up() {
+    let board = Board::setup();
+
+    assert_eq!(board.get_square(&pos(7, 0)).unwrap().owner, Side::Dark);
+    assert_eq!(board.get_square(&pos(0, 1)).unwrap().owner, Side::Light);
+    assert_eq!(EnglishDraughts.promotion_row(Side::Dark), 0);
+    assert_eq!(EnglishDraughts.promotion_row(Side::Light), 7);
+    assert!(
+        moves_per_side(&board, Side::Dark)
+            .iter()
+            .all(|mv| mv.to.row < mv.from.row)
+    );
+}
+
+#[test]
+fn computer_can_play_dark() {
+    let mut game = GameManager::new().with_controllers(Controller::Computer, Controller::Human);
+
+    game.make_ai_move().unwrap();
+
+    assert_eq!(game.current_turn, Side::Light);
+    assert_eq!(game.history()[0].from.row, 5);
+    assert!(matches!(game.make_ai_move(), Err(GameError::NotAiTurn)));
+}
+
+#[test]
+fn humans_can_play_each_other() {
+    let mut game = GameManager::new().with_controllers(Controller::Human, Controller::Human);
+    let mv = game.get_possible_moves(pos(5, 0)).remove(0);
+    game.make_move(mv).unwrap();
+
+    assert!(!game.is_computer_turn());
+    assert!(matches!(game.make_ai_move(), Err(GameError::NotAiTurn)));
+}
+
+#[test]
+fn computer_can_play_itself() {
+    let mut game = GameManager::new();
+    game.set_controller(Side::Dark, Controller::Computer);
+
+    for _ in 0..4 {
+        game.make_ai_move().unwrap();
+    }
+
+    assert_eq!(game.history().len(), 4);
+    assert_eq!(game.current_turn, Side::Dark);
+}
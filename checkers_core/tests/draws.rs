@@ -0,0 +1,228 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{king, pos, step};
+
+// Kings in opposite corners that can shuffle without ever meeting
+fn kings_only() -> GameManager {
+    let mut board = Board::empty();
+    board.set_square(&pos(7, 0), king(Side::Dark));
+    board.set_square(&pos(0, 7), king(Side::Light));
+    GameManager::from_board(board, Side::Dark)
+}
+
+fn shuffle(game: &mut GameManager) -> Result<(), GameError> {
+    let (dark, light) = if game.board.get_square(&pos(7, 0)).is_some() {
+        (step(pos(7, 0), pos(6, 1)), step(pos(0, 7), pos(1, 6)))
+    } else {
+        (step(pos(6, 1), pos(7, 0)), step(pos(1, 6), pos(0, 7)))
+    };
+    game.make_move(dark)?;
+    game.make_move(light)
+}
+
+#[test]
+fn threefold_repetition_is_a_draw() {
+    let mut game = kings_only();
+
+    shuffle(&mut game).unwrap();
+    shuffle(&mut game).unwrap();
+    shuffle(&mut game).unwrap();
+    assert!(!game.is_over());
+    shuffle(&mut game).unwrap();
+
+    assert_eq!(
+        game.result,
+        Some(GameResult {
+            winner: None,
+            reason: GameEndReason::Repetition,
+        })
+    );
+    assert!(matches!(shuffle(&mut game), Err(GameError::GameOver)));
+}
+
+#[test]
+fn frisian_king_counters_are_part_of_the_position() {
+    let mut board = Board::empty_with(FrisianDraughts);
+    board.set_square(&pos(9, 0), king(Side::Dark));
+    board.set_square(&pos(0, 7), king(Side::Light));
+    let mut game = GameManager::from_board(board, Side::Dark);
+    let shuffle = |game: &mut GameManager<FrisianDraughts>| {
+        let (dark, light) = if game.board.get_square(&pos(9, 0)).is_some() {
+            (step(pos(9, 0), pos(8, 1)), step(pos(0, 7), pos(1, 6)))
+        } else {
+            (step(pos(8, 1), pos(9, 0)), step(pos(1, 6), pos(0, 7)))
+        };
+        game.make_move(dark).unwrap();
+        game.make_move(light).unwrap();
+    };
+
+    // The pieces are back where they started after every second shuffle,
+    // but the counters only stop changing once both reach the limit
+    for _ in 0..6 {
+        shuffle(&mut game);
+        assert!(!game.is_over());
+    }
+    shuffle(&mut game);
+
+    assert_eq!(
+        game.result.map(|result| result.reason),
+        Some(GameEndReason::Repetition)
+    );
+}
+
+#[test]
+fn move_limit_is_a_draw() {
+    let mut game = kings_only().with_draw_rules(DrawRules {
+        repetitions: usize::MAX,
+        move_limit: Some(3),
+    });
+
+    shuffle(&mut game).unwrap();
+    shuffle(&mut game).unwrap();
+    assert!(!game.is_over());
+    shuffle(&mut game).unwrap();
+
+    assert_eq!(
+        game.result,
+        Some(GameResult {
+            winner: None,
+            reason: GameEndReason::MoveLimit,
+        })
+    );
+}
+
+#[test]
+fn man_move_resets_the_move_limit() {
+    let mut game = kings_only().with_draw_rules(DrawRules {
+        repetitions: usize::MAX,
+        move_limit: Some(2),
+    });
+    game.board.set_square(
+        &pos(5, 6),
+        Some(PieceData {
+            owner: Side::Dark,
+            is_king: false,
+        }),
+    );
+
+    shuffle(&mut game).unwrap();
+    game.make_move(step(pos(5, 6), pos(4, 5))).unwrap();
+    game.make_move(step(pos(1, 6), pos(0, 7))).unwrap();
+    game.make_move(step(pos(6, 1), pos(7, 0))).unwrap();
+
+    assert!(!game.is_over());
+}
+
+#[test]
+fn no_moves_is_a_win() {
+    let mut board = Board::empty();
+    board.set_square(&pos(2, 1), king(Side::Dark));
+    board.set_square(
+        &pos(1, 0),
+        Some(PieceData {
+            owner: Side::Light,
+            is_king: false,
+        }),
+    );
+    let mut game = GameManager::from_board(board, Side::Dark);
+
+    game.make_move(step(pos(2, 1), pos(3, 2))).unwrap();
+    game.make_move(step(pos(1, 0), pos(2, 1))).unwrap();
+    let capture = moves_per_side(&game.board, Side::Dark).remove(0);
+    game.make_move(capture).unwrap();
+
+    assert_eq!(
+        game.result,
+        Some(GameResult {
+            winner: Some(Side::Dark),
+            reason: GameEndReason::NoMoves,
+        })
+    );
+}
+
+#[test]
+fn resigning_hands_the_win_to_the_opponent() {
+    let mut game = GameManager::new();
+
+    game.resign(Side::Dark).unwrap();
+
+    assert_eq!(
+        game.result,
+        Some(GameResult {
+            winner: Some(Side::Light),
+            reason: GameEndReason::Resignation,
+        })
+    );
+    assert!(matches!(game.resign(Side::Light), Err(GameError::GameOver)));
+}
+
+#[test]
+fn accepted_draw_offer_ends_the_game() {
+    let mut game = kings_only();
+
+    game.offer_draw(Side::Dark).unwrap();
+    assert_eq!(game.draw_offer, Some(Side::Dark));
+    assert!(matches!(
+        game.accept_draw(Side::Dark),
+        Err(GameError::NoDrawOffer)
+    ));
+    game.accept_draw(Side::Light).unwrap();
+
+    assert_eq!(
+        game.result,
+        Some(GameResult {
+            winner: None,
+            reason: GameEndReason::Agreement,
+        })
+    );
+}
+
+#[test]
+fn declined_draw_offer_is_withdrawn() {
+    let mut game = kings_only();
+
+    game.offer_draw(Side::Dark).unwrap();
+    game.decline_draw(Side::Light).unwrap();
+
+    assert_eq!(game.draw_offer, None);
+    assert!(matches!(
+        game.accept_draw(Side::Light),
+        Err(GameError::NoDrawOffer)
+    ));
+    assert!(!game.is_over());
+}
+
+#[test]
+fn draw_offer_lapses_when_the_offering_side_moves() {
+    let mut game = kings_only();
+
+    game.offer_draw(Side::Dark).unwrap();
+    game.make_move(step(pos(7, 0), pos(6, 1))).unwrap();
+
+    assert_eq!(game.draw_offer, None);
+}
+
+#[test]
+fn draw_offer_survives_the_opponents_move() {
+    let mut game = kings_only();
+
+    game.make_move(step(pos(7, 0), pos(6, 1))).unwrap();
+    game.offer_draw(Side::Dark).unwrap();
+    game.make_move(step(pos(0, 7), pos(1, 6))).unwrap();
+
+    assert_eq!(game.draw_offer, Some(Side::Dark));
+}
+
+#[test]
+fn offering_back_accepts_a_standing_offer() {
+    let mut game = kings_only();
+
+    game.offer_draw(Side::Light).unwrap();
+    game.offer_draw(Side::Dark).unwrap();
+
+    assert_eq!(
+        game.result.map(|result| result.reason),
+        Some(GameEndReason::Agreement)
+    );
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{board_with, pos, step};
+
+#[test]
+fn men_capture_along_columns_and_rows() {
+    let board = board_with(
+        FrisianDraughts,
+        &[
+            (pos(6, 3), Side::Dark, false),
+            (pos(4, 3), Side::Light, false),
+            (pos(6, 5), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 2);
+    assert!(moves.iter().any(|m| m.to == pos(2, 3)));
+    assert!(moves.iter().any(|m| m.to == pos(6, 7)));
+}
+
+#[test]
+fn capturing_a_king_beats_capturing_a_man() {
+    let board = board_with(
+        FrisianDraughts,
+        &[
+            (pos(6, 3), Side::Dark, false),
+            (pos(5, 2), Side::Light, false),
+            (pos(5, 4), Side::Light, true),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].captured(), &[pos(5, 4)]);
+}
+
+#[test]
+fn two_men_beat_one_king() {
+    let board = board_with(
+        FrisianDraughts,
+        &[
+            (pos(6, 3), Side::Dark, false),
+            (pos(5, 4), Side::Light, true),
+            (pos(5, 2), Side::Light, false),
+            (pos(3, 2), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].captured(), &[pos(5, 2), pos(3, 2)]);
+}
+
+#[test]
+fn kings_make_at_most_three_plain_moves_in_a_row() {
+    let mut board = board_with(
+        FrisianDraughts,
+        &[
+            (pos(9, 0), Side::Dark, true),
+            (pos(6, 9), Side::Dark, false),
+            (pos(0, 1), Side::Light, false),
+        ],
+    );
+
+    board.apply_move(&step(pos(9, 0), pos(8, 1))).unwrap();
+    board.apply_move(&step(pos(8, 1), pos(9, 0))).unwrap();
+    board.apply_move(&step(pos(9, 0), pos(8, 1))).unwrap();
+    assert_eq!(board.state().king_moves(Side::Dark), 3);
+
+    let moves = moves_per_side(&board, Side::Dark);
+    assert!(!moves.is_empty());
+    assert!(moves.iter().all(|m| m.from == pos(6, 9)));
+
+    board.apply_move(&step(pos(6, 9), pos(5, 8))).unwrap();
+    assert_eq!(board.state().king_moves(Side::Dark), 0);
+    assert!(
+        moves_per_side(&board, Side::Dark)
+            .iter()
+            .any(|m| m.from == pos(8, 1))
+    );
+}
+
+#[test]
+fn king_limit_does_not_apply_without_men() {
+    let mut board = board_with(
+        FrisianDraughts,
+        &[
+            (pos(9, 0), Side::Dark, true),
+            (pos(0, 1), Side::Light, false),
+        ],
+    );
+
+    board.apply_move(&step(pos(9, 0), pos(8, 1))).unwrap();
+    board.apply_move(&step(pos(8, 1), pos(9, 0))).unwrap();
+    board.apply_move(&step(pos(9, 0), pos(8, 1))).unwrap();
+
+    assert!(!moves_per_side(&board, Side::Dark).is_empty());
+}
+
+#[test]
+fn game_manager_enforces_the_king_limit() {
+    let board = board_with(
+        FrisianDraughts,
+        &[
+            (pos(9, 0), Side::Dark, true),
+            (pos(6, 9), Side::Dark, false),
+            (pos(0, 1), Side::Light, true),
+            (pos(3, 4), Side::Light, false),
+        ],
+    );
+    let mut game = GameManager::from_board(board, Side::Dark);
+
+    for (dark, light) in [
+        (step(pos(9, 0), pos(8, 1)), step(pos(0, 1), pos(1, 2))),
+        (step(pos(8, 1), pos(9, 0)), step(pos(1, 2), pos(0, 1))),
+        (step(pos(9, 0), pos(8, 1)), step(pos(0, 1), pos(1, 2))),
+    ] {
+        game.make_move(dark).unwrap();
+        game.make_move(light).unwrap();
+    }
+
+    assert!(matches!(
+        game.make_move(step(pos(8, 1), pos(9, 0))),
+        Err(GameError::InvalidMove)
+    ));
+}
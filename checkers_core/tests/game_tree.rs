@@ -0,0 +1,98 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{pos, step};
+
+#[test]
+fn position_is_replayed_from_the_start() {
+    let mut tree = GameTree::setup();
+    tree.play(step(pos(5, 0), pos(4, 1))).unwrap();
+    tree.play(step(pos(2, 1), pos(3, 2))).unwrap();
+
+    let mut expected = Board::setup();
+    expected.apply_move(&step(pos(5, 0), pos(4, 1))).unwrap();
+    expected.apply_move(&step(pos(2, 1), pos(3, 2))).unwrap();
+
+    let (board, side) = tree.current_position().unwrap();
+    assert_eq!(board, expected);
+    assert_eq!(side, Side::Dark);
+}
+
+#[test]
+fn illegal_moves_are_refused() {
+    let mut tree = GameTree::setup();
+
+    let result = tree.play(step(pos(2, 1), pos(3, 2)));
+
+    assert!(matches!(result, Err(TreeError::InvalidMove)));
+    assert_eq!(tree.current(), tree.root());
+}
+
+#[test]
+fn alternatives_become_variations() {
+    let mut tree = GameTree::setup();
+    let main = tree.play(step(pos(5, 0), pos(4, 1))).unwrap();
+    tree.back();
+    let variation = tree.play(step(pos(5, 2), pos(4, 3))).unwrap();
+
+    assert_eq!(
+        tree.node(tree.root()).unwrap().children(),
+        [main, variation]
+    );
+    assert_eq!(tree.mainline(), [&step(pos(5, 0), pos(4, 1))]);
+
+    // Playing a known move follows it rather than adding a duplicate
+    tree.back();
+    assert_eq!(tree.play(step(pos(5, 0), pos(4, 1))).unwrap(), main);
+    assert_eq!(tree.node(tree.root()).unwrap().children().len(), 2);
+}
+
+#[test]
+fn promoting_a_variation_makes_it_the_mainline() {
+    let mut tree = GameTree::setup();
+    tree.play(step(pos(5, 0), pos(4, 1))).unwrap();
+    tree.play(step(pos(2, 1), pos(3, 2))).unwrap();
+    tree.go_to(tree.root()).unwrap();
+    tree.play(step(pos(5, 2), pos(4, 3))).unwrap();
+    let deep = tree.play(step(pos(2, 3), pos(3, 4))).unwrap();
+
+    tree.promote(deep).unwrap();
+
+    assert_eq!(
+        tree.mainline(),
+        [&step(pos(5, 2), pos(4, 3)), &step(pos(2, 3), pos(3, 4))]
+    );
+}
+
+#[test]
+fn navigation_follows_the_mainline() {
+    let mut tree = GameTree::setup();
+    let first = tree.play(step(pos(5, 0), pos(4, 1))).unwrap();
+    let second = tree.play(step(pos(2, 1), pos(3, 2))).unwrap();
+
+    tree.go_to(tree.root()).unwrap();
+    assert!(!tree.back());
+    assert!(tree.forward());
+    assert_eq!(tree.current(), first);
+    assert!(tree.forward());
+    assert_eq!(tree.current(), second);
+    assert!(!tree.forward());
+}
+
+#[test]
+fn deleting_a_branch_removes_its_moves() {
+    let mut tree = GameTree::setup();
+    let main = tree.play(step(pos(5, 0), pos(4, 1))).unwrap();
+    tree.back();
+    let variation = tree.play(step(pos(5, 2), pos(4, 3))).unwrap();
+    let reply = tree.play(step(pos(2, 1), pos(3, 2))).unwrap();
+
+    tree.delete(variation).unwrap();
+
+    assert_eq!(tree.current(), tree.root());
+    assert_eq!(tree.node(tree.root()).unwrap().children(), [main]);
+    assert!(tree.node(variation).is_none());
+    assert!(tree.node(reply).is_none());
+    assert!(matches!(tree.go_to(reply), Err(TreeError::UnknownNode)));
+    assert!(matches!(tree.delete(tree.root()), Err(TreeError::RootNode)));
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{man, pos};
+
+#[test]
+fn side_that_loses_every_piece_wins() {
+    let mut board = Board::empty_with(GiveawayDraughts);
+    board.set_square(&pos(2, 5), man(Side::Light));
+    board.set_square(&pos(4, 3), man(Side::Dark));
+    board.set_square(&pos(6, 1), man(Side::Dark));
+    let mut game = GameManager::from_board(board, Side::Light);
+
+    game.make_move(Move {
+        from: pos(2, 5),
+        to: pos(3, 4),
+        move_type: MoveType::Move,
+    })
+    .unwrap();
+    let capture = moves_per_side(&game.board, Side::Dark).remove(0);
+    game.make_move(capture).unwrap();
+
+    assert_eq!(
+        game.result,
+        Some(GameResult {
+            winner: Some(Side::Light),
+            reason: GameEndReason::NoMoves,
+        })
+    );
+}
+
+#[test]
+fn ai_gives_its_pieces_away() {
+    let mut board = Board::empty_with(GiveawayDraughts);
+    board.set_square(&pos(2, 5), man(Side::Light));
+    board.set_square(&pos(4, 3), man(Side::Dark));
+    board.set_square(&pos(6, 1), man(Side::Dark));
+
+    let mv = decide_move(&board, Side::Light, 2).unwrap();
+    assert_eq!(mv.to, pos(3, 4));
+}
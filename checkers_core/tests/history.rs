@@ -0,0 +1,166 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{jump, man, pos, step};
+
+fn double_jump_game() -> GameManager {
+    let mut board = Board::empty();
+    board.set_square(&pos(6, 1), man(Side::Dark));
+    board.set_square(&pos(5, 2), man(Side::Light));
+    board.set_square(&pos(3, 4), man(Side::Light));
+    board.set_square(&pos(0, 1), man(Side::Light));
+    GameManager::from_board(board, Side::Dark)
+}
+
+#[test]
+fn undo_restores_the_board_and_the_turn() {
+    let mut game = GameManager::new();
+    let start = game.board.clone();
+
+    game.make_move(step(pos(5, 0), pos(4, 1))).unwrap();
+    let undone = game.undo();
+
+    assert_eq!(undone, Some(step(pos(5, 0), pos(4, 1))));
+    assert_eq!(game.board, start);
+    assert_eq!(game.current_turn, Side::Dark);
+    assert!(game.history().is_empty());
+    assert_eq!(game.undo(), None);
+}
+
+#[test]
+fn redo_replays_an_undone_move() {
+    let mut game = GameManager::new();
+    game.make_move(step(pos(5, 0), pos(4, 1))).unwrap();
+    let after = game.board.clone();
+
+    game.undo();
+    assert_eq!(game.redo(), Some(step(pos(5, 0), pos(4, 1))));
+
+    assert_eq!(game.board, after);
+    assert_eq!(game.current_turn, Side::Light);
+    assert_eq!(game.history(), [step(pos(5, 0), pos(4, 1))]);
+    assert_eq!(game.redo(), None);
+}
+
+#[test]
+fn a_new_move_drops_the_redo_list() {
+    let mut game = GameManager::new();
+    game.make_move(step(pos(5, 0), pos(4, 1))).unwrap();
+    game.undo();
+
+    game.make_move(step(pos(5, 2), pos(4, 3))).unwrap();
+
+    assert_eq!(game.redo(), None);
+    assert_eq!(game.history(), [step(pos(5, 2), pos(4, 3))]);
+}
+
+#[test]
+fn undo_steps_back_into_an_unfinished_capture() {
+    let mut game = double_jump_game();
+    game.make_move(jump(pos(6, 1), pos(4, 3))).unwrap();
+    game.make_move(jump(pos(4, 3), pos(2, 5))).unwrap();
+    assert_eq!(game.current_turn, Side::Light);
+    assert!(game.pending_capture.is_none());
+
+    game.undo();
+
+    assert_eq!(game.current_turn, Side::Dark);
+    assert_eq!(
+        game.pending_capture
+            .as_ref()
+            .map(|pending| pending.position),
+        Some(pos(4, 3))
+    );
+    assert_eq!(
+        game.get_possible_moves(pos(4, 3)),
+        vec![jump(pos(4, 3), pos(2, 5))]
+    );
+
+    game.undo();
+
+    assert!(game.pending_capture.is_none());
+    assert_eq!(game.board, double_jump_game().board);
+}
+
+#[test]
+fn undo_reopens_a_finished_game() {
+    let mut board = Board::empty();
+    board.set_square(&pos(5, 2), man(Side::Dark));
+    board.set_square(&pos(4, 3), man(Side::Light));
+    let mut game = GameManager::from_board(board, Side::Dark);
+
+    let capture = game.get_possible_moves(pos(5, 2)).remove(0);
+    game.make_move(capture).unwrap();
+    assert!(game.is_over());
+
+    game.undo();
+
+    assert_eq!(game.result, None);
+    assert_eq!(game.current_turn, Side::Dark);
+    assert!(game.board.get_square(&pos(4, 3)).is_some());
+}
+
+#[test]
+fn undo_brings_back_a_lapsed_draw_offer() {
+    let mut game = GameManager::new();
+    game.offer_draw(Side::Dark).unwrap();
+    game.make_move(step(pos(5, 0), pos(4, 1))).unwrap();
+    assert_eq!(game.draw_offer, None);
+
+    game.undo();
+
+    assert_eq!(game.draw_offer, Some(Side::Dark));
+}
+
+#[test]
+fn capture_entered_a_jump_at_a_time_is_one_history_entry() {
+    let mut game = double_jump_game();
+    let start = game.board.clone();
+    game.make_move(jump(pos(6, 1), pos(4, 3))).unwrap();
+    assert!(game.history().is_empty());
+
+    game.make_move(jump(pos(4, 3), pos(2, 5))).unwrap();
+
+    assert_eq!(game.history().len(), 1);
+    let chain = &game.history()[0];
+    assert_eq!(chain.from, pos(6, 1));
+    assert_eq!(chain.captured(), [pos(5, 2), pos(3, 4)]);
+
+    let mut replayed = start.clone();
+    for mv in game.history() {
+        replayed.apply_move(mv).unwrap();
+    }
+    assert_eq!(replayed, game.board);
+
+    let mut tree = GameTree::new(start, Side::Dark);
+    for mv in game.history() {
+        tree.play(mv.clone()).unwrap();
+    }
+    assert_eq!(tree.current_position().unwrap(), (game.board, Side::Light));
+}
+
+#[test]
+fn undo_and_redo_walk_through_a_long_game() {
+    let mut game = GameManager::new().with_controllers(Controller::Computer, Controller::Computer);
+    let mut boards = vec![game.board.clone()];
+    for _ in 0..12 {
+        if game.is_over() {
+            break;
+        }
+        game.make_ai_move().unwrap();
+        boards.push(game.board.clone());
+    }
+
+    for expected in boards.iter().rev().skip(1) {
+        game.undo().unwrap();
+        assert_eq!(game.board, *expected);
+    }
+    assert_eq!(game.undo(), None);
+    assert!(game.history().is_empty());
+
+    for expected in boards.iter().skip(1) {
+        game.redo().unwrap();
+        assert_eq!(game.board, *expected);
+    }
+    assert_eq!(game.history().len(), boards.len() - 1);
+}
@@ -0,0 +1,147 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{board_with, pos};
+
+#[test]
+fn setup_has_twenty_men_a_side() {
+    let board = Board::setup_with(InternationalDraughts);
+
+    assert_eq!(board.size(), 10);
+    assert_eq!(board.all_pieces_of_side(Side::Dark).len(), 20);
+    assert_eq!(board.all_pieces_of_side(Side::Light).len(), 20);
+    assert_eq!(moves_per_side(&board, Side::Dark).len(), 9);
+}
+
+#[test]
+fn men_capture_backwards() {
+    let board = board_with(
+        InternationalDraughts,
+        &[
+            (pos(5, 4), Side::Dark, false),
+            (pos(6, 5), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].to, pos(7, 6));
+}
+
+#[test]
+fn kings_fly_along_open_diagonals() {
+    let board = board_with(
+        InternationalDraughts,
+        &[
+            (pos(9, 0), Side::Dark, true),
+            (pos(0, 1), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 9);
+    assert!(moves.iter().any(|m| m.to == pos(0, 9)));
+}
+
+#[test]
+fn flying_king_lands_anywhere_behind_the_captured_piece() {
+    let board = board_with(
+        InternationalDraughts,
+        &[
+            (pos(9, 0), Side::Dark, true),
+            (pos(6, 3), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 6);
+    assert!(moves.iter().all(|m| m.captured() == [pos(6, 3)]));
+}
+
+#[test]
+fn capture_taking_the_most_pieces_is_mandatory() {
+    let board = board_with(
+        InternationalDraughts,
+        &[
+            // A single capture for this man
+            (pos(9, 0), Side::Dark, false),
+            (pos(8, 1), Side::Light, false),
+            // A double capture for this one
+            (pos(9, 6), Side::Dark, false),
+            (pos(8, 5), Side::Light, false),
+            (pos(6, 5), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].from, pos(9, 6));
+    assert_eq!(moves[0].captured(), &[pos(8, 5), pos(6, 5)]);
+}
+
+#[test]
+fn captured_pieces_cannot_be_jumped_twice() {
+    // The king could loop back over (4, 5) if jumped pieces vanished at once
+    let board = board_with(
+        InternationalDraughts,
+        &[
+            (pos(7, 2), Side::Dark, true),
+            (pos(5, 4), Side::Light, false),
+            (pos(3, 4), Side::Light, false),
+            (pos(3, 2), Side::Light, false),
+            (pos(5, 2), Side::Light, false),
+        ],
+    );
+
+    for mv in moves_per_side(&board, Side::Dark) {
+        let captured = mv.captured();
+        for (index, taken) in captured.iter().enumerate() {
+            assert!(!captured[index + 1..].contains(taken));
+        }
+    }
+}
+
+#[test]
+fn man_passing_the_last_row_mid_capture_stays_a_man() {
+    let board = board_with(
+        InternationalDraughts,
+        &[
+            (pos(2, 1), Side::Dark, false),
+            (pos(1, 2), Side::Light, false),
+            (pos(1, 4), Side::Light, false),
+            (pos(5, 8), Side::Light, false),
+        ],
+    );
+    let mut game = GameManager::from_board(board, Side::Dark);
+    let moves = moves_per_side(&game.board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].to, pos(2, 5));
+
+    game.make_move(moves[0].clone()).unwrap();
+    assert!(!game.board.get_square(&pos(2, 5)).unwrap().is_king);
+}
+
+#[test]
+fn man_ending_on_the_last_row_is_crowned() {
+    let board = board_with(
+        InternationalDraughts,
+        &[
+            (pos(2, 1), Side::Dark, false),
+            (pos(1, 2), Side::Light, false),
+            (pos(5, 8), Side::Light, false),
+        ],
+    );
+    let mut game = GameManager::from_board(board, Side::Dark);
+    let moves = moves_per_side(&game.board, Side::Dark);
+
+    game.make_move(moves[0].clone()).unwrap();
+    assert!(game.board.get_square(&pos(0, 3)).unwrap().is_king);
+}
+
+#[test]
+fn ai_plays_on_the_larger_board() {
+    let board = Board::setup_with(InternationalDraughts);
+
+    assert!(decide_move(&board, Side::Light, 3).is_some());
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{board_with, pos};
+
+#[test]
+fn board_is_turned_with_a_light_bottom_left_corner() {
+    let board = Board::setup_with(ItalianDraughts);
+
+    assert!(board.get_square(&pos(7, 0)).is_none());
+    assert!(board.get_square(&pos(7, 7)).is_some());
+    assert_eq!(board.all_pieces_of_side(Side::Dark).len(), 12);
+    assert_eq!(moves_per_side(&board, Side::Dark).len(), 7);
+}
+
+#[test]
+fn men_cannot_capture_kings() {
+    let board = board_with(
+        ItalianDraughts,
+        &[
+            (pos(5, 3), Side::Dark, false),
+            (pos(4, 4), Side::Light, true),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert!(!moves.is_empty());
+    assert!(moves.iter().all(|m| !m.is_capture()));
+}
+
+#[test]
+fn capturing_with_a_king_is_preferred() {
+    let board = board_with(
+        ItalianDraughts,
+        &[
+            (pos(5, 1), Side::Dark, false),
+            (pos(4, 2), Side::Light, false),
+            (pos(7, 7), Side::Dark, true),
+            (pos(6, 6), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].from, pos(7, 7));
+}
+
+#[test]
+fn capturing_the_most_kings_is_preferred() {
+    let board = board_with(
+        ItalianDraughts,
+        &[
+            (pos(4, 4), Side::Dark, true),
+            (pos(3, 3), Side::Light, false),
+            (pos(5, 5), Side::Light, true),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].captured(), &[pos(5, 5)]);
+}
+
+#[test]
+fn capturing_a_king_first_is_preferred() {
+    let board = board_with(
+        ItalianDraughts,
+        &[
+            (pos(4, 4), Side::Dark, true),
+            // King then man
+            (pos(3, 5), Side::Light, true),
+            (pos(1, 5), Side::Light, false),
+            // Man then king
+            (pos(5, 3), Side::Light, false),
+            (pos(5, 1), Side::Light, true),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].captured(), &[pos(3, 5), pos(1, 5)]);
+}
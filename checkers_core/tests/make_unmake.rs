@@ -0,0 +1,75 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{piece, pos};
+
+// Plays every legal move for `side` and checks that taking it back leaves
+// the board exactly as it was
+fn assert_round_trips<R: RuleSet>(board: &Board<R>, side: Side) {
+    let moves = moves_per_side(board, side);
+    assert!(!moves.is_empty());
+
+    let mut played = board.clone();
+    for mv in moves {
+        let undo = played.apply_move(&mv).unwrap();
+        assert_ne!(&played, board, "{mv:?} changed nothing");
+        played.unmake_move(&mv, undo);
+        assert_eq!(&played, board, "{mv:?} was not taken back");
+    }
+}
+
+#[test]
+fn opening_moves_are_taken_back() {
+    assert_round_trips(&Board::setup(), Side::Dark);
+    assert_round_trips(&Board::setup(), Side::Light);
+    assert_round_trips(&Board::setup_with(InternationalDraughts), Side::Dark);
+}
+
+#[test]
+fn crowning_capture_is_taken_back() {
+    let mut board = Board::empty();
+    board.set_square(&pos(2, 1), piece(Side::Dark, false));
+    board.set_square(&pos(1, 2), piece(Side::Light, false));
+    board.set_square(&pos(1, 6), piece(Side::Light, false));
+
+    let mut played = board.clone();
+    let mv = moves_per_side(&board, Side::Dark).remove(0);
+    let undo = played.apply_move(&mv).unwrap();
+    assert!(played.get_square(&pos(0, 3)).unwrap().is_king);
+
+    played.unmake_move(&mv, undo);
+
+    assert_eq!(played, board);
+    assert!(!played.get_square(&pos(2, 1)).unwrap().is_king);
+}
+
+#[test]
+fn flying_king_chain_is_taken_back() {
+    let mut board = Board::empty_with(InternationalDraughts);
+    board.set_square(&pos(9, 0), piece(Side::Dark, true));
+    board.set_square(&pos(6, 3), piece(Side::Light, false));
+    board.set_square(&pos(2, 5), piece(Side::Light, false));
+    board.set_square(&pos(4, 7), piece(Side::Light, true));
+
+    assert_round_trips(&board, Side::Dark);
+}
+
+#[test]
+fn huffed_piece_is_put_back() {
+    let mut board = Board::empty_with(CasualDraughts { huffing: true });
+    board.set_square(&pos(5, 2), piece(Side::Dark, false));
+    board.set_square(&pos(4, 3), piece(Side::Light, false));
+    board.set_square(&pos(6, 7), piece(Side::Dark, false));
+
+    assert_round_trips(&board, Side::Dark);
+}
+
+#[test]
+fn variant_state_is_restored() {
+    let mut board = Board::empty_with(FrisianDraughts);
+    board.set_square(&pos(9, 0), piece(Side::Dark, true));
+    board.set_square(&pos(6, 9), piece(Side::Dark, false));
+    board.set_square(&pos(0, 1), piece(Side::Light, false));
+
+    assert_round_trips(&board, Side::Dark);
+}
@@ -0,0 +1,235 @@
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use checkers_core::prelude::*;
+use common::{board_with, piece, pos};
+
+// Counts the allocations made by each thread, so that a test can tell
+// whether the code it runs allocates
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+// Generates the moves of both sides into one list until it has room for
+// them, then checks that doing it again allocates nothing
+fn assert_reuse_does_not_allocate<R: RuleSet>(board: &Board<R>) {
+    let mut list = MoveList::new();
+    for _ in 0..2 {
+        for side in [Side::Dark, Side::Light] {
+            generate_moves(board, side, &mut list);
+        }
+    }
+
+    let before = allocations();
+    for side in [Side::Dark, Side::Light] {
+        generate_moves(board, side, &mut list);
+    }
+    assert_eq!(allocations(), before, "{:?}", board.rules());
+}
+
+// Checks the quick queries and the move list against `moves_per_side`
+fn assert_consistent<R: RuleSet>(board: &Board<R>) {
+    let mut list = MoveList::new();
+    for side in [Side::Dark, Side::Light] {
+        let moves = moves_per_side(board, side);
+
+        generate_moves(board, side, &mut list);
+        assert_eq!(&*list, moves.as_slice());
+        assert_eq!(has_any_move(board, side), !moves.is_empty());
+        assert_eq!(
+            has_capture(board, side),
+            moves.iter().any(|mv| mv.is_capture())
+        );
+
+        for piece in board.all_pieces_of_side(side) {
+            let own: Vec<Move> = moves
+                .iter()
+                .filter(|mv| mv.from == piece.position)
+                .cloned()
+                .collect();
+            assert_eq!(moves_per_piece(board, &piece), own);
+        }
+    }
+}
+
+#[test]
+fn opening_positions_agree() {
+    assert_consistent(&Board::setup());
+    assert_consistent(&Board::setup_with(InternationalDraughts));
+    assert_consistent(&Board::setup_with(TurkishDraughts));
+    assert_consistent(&Board::setup_with(ItalianDraughts));
+}
+
+#[test]
+fn capture_positions_agree() {
+    let mut board = Board::empty();
+    board.set_square(&pos(5, 2), piece(Side::Dark, false));
+    board.set_square(&pos(4, 3), piece(Side::Light, false));
+    board.set_square(&pos(2, 3), piece(Side::Light, true));
+    board.set_square(&pos(7, 0), piece(Side::Dark, true));
+    assert_consistent(&board);
+
+    let mut board = Board::empty_with(InternationalDraughts);
+    board.set_square(&pos(9, 0), piece(Side::Dark, true));
+    board.set_square(&pos(5, 4), piece(Side::Light, false));
+    board.set_square(&pos(3, 2), piece(Side::Light, false));
+    board.set_square(&pos(6, 7), piece(Side::Dark, false));
+    assert_consistent(&board);
+
+    let mut board = Board::empty_with(CasualDraughts { huffing: true });
+    board.set_square(&pos(5, 2), piece(Side::Dark, false));
+    board.set_square(&pos(4, 3), piece(Side::Light, false));
+    board.set_square(&pos(6, 7), piece(Side::Dark, false));
+    assert_consistent(&board);
+}
+
+#[test]
+fn piece_with_a_shorter_capture_has_no_move() {
+    let mut board = Board::empty_with(InternationalDraughts);
+    board.set_square(&pos(6, 1), piece(Side::Dark, false));
+    board.set_square(&pos(5, 2), piece(Side::Light, false));
+    board.set_square(&pos(6, 7), piece(Side::Dark, false));
+    board.set_square(&pos(5, 6), piece(Side::Light, false));
+    board.set_square(&pos(3, 6), piece(Side::Light, false));
+
+    let dark = board.all_pieces_of_side(Side::Dark);
+    let short = dark.iter().find(|p| p.position == pos(6, 1)).unwrap();
+    assert!(moves_per_piece(&board, short).is_empty());
+    let long = dark.iter().find(|p| p.position == pos(6, 7)).unwrap();
+    assert_eq!(moves_per_piece(&board, long).len(), 1);
+    assert_consistent(&board);
+}
+
+#[test]
+fn blocked_side_has_no_move() {
+    let mut board = Board::empty();
+    board.set_square(&pos(0, 7), piece(Side::Dark, false));
+    board.set_square(&pos(6, 1), piece(Side::Light, false));
+    board.set_square(&pos(5, 2), piece(Side::Light, false));
+    board.set_square(&pos(7, 0), piece(Side::Dark, false));
+
+    assert!(!has_any_move(&board, Side::Dark));
+    assert!(has_any_move(&board, Side::Light));
+    assert_consistent(&board);
+}
+
+#[test]
+fn frisian_king_limit_counts() {
+    let mut board = Board::empty_with(FrisianDraughts);
+    board.set_square(&pos(9, 0), piece(Side::Dark, true));
+    board.set_square(&pos(6, 9), piece(Side::Dark, false));
+    board.set_square(&pos(0, 1), piece(Side::Light, false));
+    for (from, to) in [((9, 0), (8, 1)), ((8, 1), (9, 0)), ((9, 0), (8, 1))] {
+        board
+            .apply_move(&Move {
+                from: pos(from.0, from.1),
+                to: pos(to.0, to.1),
+                move_type: MoveType::Move,
+            })
+            .unwrap();
+    }
+
+    assert_consistent(&board);
+}
+
+#[test]
+fn list_is_refilled_on_reuse() {
+    let mut list = MoveList::new();
+    generate_moves(&Board::setup(), Side::Dark, &mut list);
+    assert_eq!(list.len(), 7);
+
+    generate_moves(&Board::empty(), Side::Dark, &mut list);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn reused_list_does_not_allocate() {
+    assert_reuse_does_not_allocate(&Board::setup());
+    assert_reuse_does_not_allocate(&Board::setup_with(InternationalDraughts));
+
+    // Multi-jump chains, on the bit masks and on a grid
+    let chains = [
+        (pos(7, 0), Side::Dark, false),
+        (pos(6, 1), Side::Light, false),
+        (pos(4, 3), Side::Light, false),
+        (pos(2, 3), Side::Light, false),
+        (pos(0, 7), Side::Light, true),
+    ];
+    assert_reuse_does_not_allocate(&board_with(EnglishDraughts, &chains));
+    assert_reuse_does_not_allocate(&board_with(RussianDraughts, &chains));
+    assert_reuse_does_not_allocate(&board_with(
+        InternationalDraughts,
+        &[
+            (pos(9, 0), Side::Dark, true),
+            (pos(6, 3), Side::Light, false),
+            (pos(3, 4), Side::Light, false),
+            (pos(6, 9), Side::Dark, false),
+            (pos(5, 8), Side::Light, false),
+        ],
+    ));
+    assert_reuse_does_not_allocate(&board_with(
+        FrisianDraughts,
+        &[
+            (pos(9, 0), Side::Dark, true),
+            (pos(6, 9), Side::Dark, false),
+            (pos(5, 8), Side::Light, false),
+            (pos(0, 1), Side::Light, true),
+        ],
+    ));
+    assert_reuse_does_not_allocate(&board_with(
+        TurkishDraughts,
+        &[
+            (pos(2, 2), Side::Dark, true),
+            (pos(2, 4), Side::Light, false),
+            (pos(4, 5), Side::Light, false),
+            (pos(5, 3), Side::Light, false),
+            (pos(3, 2), Side::Light, false),
+            (pos(2, 6), Side::Light, false),
+        ],
+    ));
+    assert_reuse_does_not_allocate(&board_with(
+        CasualDraughts { huffing: true },
+        &[
+            (pos(5, 2), Side::Dark, false),
+            (pos(4, 3), Side::Light, false),
+            (pos(6, 7), Side::Dark, false),
+        ],
+    ));
+}
+
+#[test]
+fn long_chains_keep_every_square() {
+    let jumps = CaptureSequence::INLINE_JUMPS + 3;
+    let path: Vec<Position> = (0..jumps).map(|i| pos(i, i)).collect();
+    let captured: Vec<Position> = (0..jumps).map(|i| pos(i, i + 1)).collect();
+
+    let chain = CaptureSequence::new(&path, &captured);
+    assert_eq!(chain.path(), path);
+    assert_eq!(chain.captured(), captured);
+    assert_eq!(
+        CaptureSequence::new(&path[..2], &captured[..2]),
+        CaptureSequence::new(&[pos(0, 0), pos(1, 1)], &[pos(0, 1), pos(1, 2)])
+    );
+}
@@ -0,0 +1,279 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{board_with, jump, man, pos};
+
+// Men on `pieces`, plus a spare light man in the corner so light still has
+// a reply
+fn chain_board<R: RuleSet>(rules: R, pieces: &[(Position, Side)]) -> Board<R> {
+    let mut board = board_with(rules, &[(pos(0, 1), Side::Light, false)]);
+    for (position, owner) in pieces {
+        board.set_square(position, man(*owner));
+    }
+    board
+}
+
+fn double_jump_board() -> Board {
+    chain_board(
+        EnglishDraughts,
+        &[
+            (pos(6, 1), Side::Dark),
+            (pos(5, 2), Side::Light),
+            (pos(3, 4), Side::Light),
+        ],
+    )
+}
+
+fn triple_jump_board() -> Board {
+    chain_board(
+        EnglishDraughts,
+        &[
+            (pos(7, 0), Side::Dark),
+            (pos(6, 1), Side::Light),
+            (pos(4, 3), Side::Light),
+            (pos(2, 5), Side::Light),
+        ],
+    )
+}
+
+#[test]
+fn double_jump_is_listed_as_one_move() {
+    let board = double_jump_board();
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].from, pos(6, 1));
+    assert_eq!(moves[0].to, pos(2, 5));
+    assert_eq!(moves[0].captured(), &[pos(5, 2), pos(3, 4)]);
+}
+
+#[test]
+fn double_jump_as_one_move_ends_the_turn() {
+    let mut game = GameManager::from_board(double_jump_board(), Side::Dark);
+    let chain = moves_per_side(&game.board, Side::Dark).remove(0);
+
+    game.make_move(chain).unwrap();
+
+    assert_eq!(game.current_turn, Side::Light);
+    assert!(game.pending_capture.is_none());
+    assert!(game.board.get_square(&pos(5, 2)).is_none());
+    assert!(game.board.get_square(&pos(3, 4)).is_none());
+    assert!(game.board.get_square(&pos(2, 5)).is_some());
+}
+
+#[test]
+fn double_jump_one_hop_at_a_time() {
+    let mut game = GameManager::from_board(double_jump_board(), Side::Dark);
+
+    game.make_move(jump(pos(6, 1), pos(4, 3))).unwrap();
+    assert_eq!(game.current_turn, Side::Dark);
+    assert_eq!(
+        game.pending_capture.as_ref().map(|p| p.position),
+        Some(pos(4, 3))
+    );
+    assert_eq!(
+        game.get_possible_moves(pos(4, 3)),
+        vec![jump(pos(4, 3), pos(2, 5))]
+    );
+
+    game.make_move(jump(pos(4, 3), pos(2, 5))).unwrap();
+    assert_eq!(game.current_turn, Side::Light);
+    assert!(game.pending_capture.is_none());
+    assert!(game.board.get_square(&pos(3, 4)).is_none());
+}
+
+#[test]
+fn hop_that_is_not_part_of_a_chain_is_rejected() {
+    let mut game = GameManager::from_board(double_jump_board(), Side::Dark);
+
+    assert!(matches!(
+        game.make_move(jump(pos(6, 1), pos(4, 5))),
+        Err(GameError::InvalidMove)
+    ));
+    assert!(game.pending_capture.is_none());
+}
+
+#[test]
+fn only_the_capturing_piece_may_continue() {
+    let mut board = double_jump_board();
+    board.set_square(&pos(6, 7), man(Side::Dark));
+    board.set_square(&pos(5, 6), man(Side::Light));
+    let mut game = GameManager::from_board(board, Side::Dark);
+
+    game.make_move(jump(pos(6, 1), pos(4, 3))).unwrap();
+
+    assert!(game.get_possible_moves(pos(6, 7)).is_empty());
+    assert!(matches!(
+        game.make_move(jump(pos(6, 7), pos(4, 5))),
+        Err(GameError::CaptureInProgress)
+    ));
+    assert_eq!(
+        game.pending_capture.as_ref().map(|p| p.position),
+        Some(pos(4, 3))
+    );
+}
+
+#[test]
+fn triple_jump_is_listed_as_one_move() {
+    let board = triple_jump_board();
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].to, pos(1, 6));
+    assert_eq!(moves[0].captured(), &[pos(6, 1), pos(4, 3), pos(2, 5)]);
+}
+
+#[test]
+fn triple_jump_one_hop_at_a_time() {
+    let mut game = GameManager::from_board(triple_jump_board(), Side::Dark);
+
+    game.make_move(jump(pos(7, 0), pos(5, 2))).unwrap();
+    game.make_move(jump(pos(5, 2), pos(3, 4))).unwrap();
+    assert_eq!(game.current_turn, Side::Dark);
+
+    game.make_move(jump(pos(3, 4), pos(1, 6))).unwrap();
+    assert_eq!(game.current_turn, Side::Light);
+    for taken in [pos(6, 1), pos(4, 3), pos(2, 5)] {
+        assert!(game.board.get_square(&taken).is_none());
+    }
+}
+
+#[test]
+fn rest_of_chain_can_be_played_as_one_move() {
+    let mut game = GameManager::from_board(triple_jump_board(), Side::Dark);
+
+    game.make_move(jump(pos(7, 0), pos(5, 2))).unwrap();
+    game.make_move(Move {
+        from: pos(5, 2),
+        to: pos(1, 6),
+        move_type: MoveType::Capture(CaptureSequence::new(
+            &[pos(3, 4), pos(1, 6)],
+            &[pos(4, 3), pos(2, 5)],
+        )),
+    })
+    .unwrap();
+
+    assert_eq!(game.current_turn, Side::Light);
+    assert!(game.pending_capture.is_none());
+}
+
+#[test]
+fn branching_chain_offers_every_follow_up() {
+    let board = chain_board(
+        EnglishDraughts,
+        &[
+            (pos(6, 3), Side::Dark),
+            (pos(5, 4), Side::Light),
+            (pos(3, 4), Side::Light),
+            (pos(3, 6), Side::Light),
+        ],
+    );
+    let mut game = GameManager::from_board(board, Side::Dark);
+
+    assert_eq!(moves_per_side(&game.board, Side::Dark).len(), 2);
+
+    game.make_move(jump(pos(6, 3), pos(4, 5))).unwrap();
+    let follow_ups = game.get_possible_moves(pos(4, 5));
+    assert_eq!(follow_ups.len(), 2);
+    assert!(follow_ups.contains(&jump(pos(4, 5), pos(2, 3))));
+    assert!(follow_ups.contains(&jump(pos(4, 5), pos(2, 7))));
+}
+
+#[test]
+fn ai_finishes_its_own_chain() {
+    let mut board = Board::empty();
+    board.set_square(&pos(1, 0), man(Side::Light));
+    board.set_square(&pos(2, 1), man(Side::Dark));
+    board.set_square(&pos(4, 3), man(Side::Dark));
+    board.set_square(&pos(7, 6), man(Side::Dark));
+    let mut game = GameManager::from_board(board, Side::Light);
+
+    game.make_move(jump(pos(1, 0), pos(3, 2))).unwrap();
+    game.make_ai_move().unwrap();
+
+    assert_eq!(game.current_turn, Side::Dark);
+    assert!(game.board.get_square(&pos(5, 4)).is_some());
+}
+
+#[test]
+fn ai_picks_the_safe_way_to_finish_a_chain() {
+    let mut board = Board::empty();
+    board.set_square(&pos(6, 1), man(Side::Dark));
+    board.set_square(&pos(5, 2), man(Side::Light));
+    board.set_square(&pos(3, 2), man(Side::Light));
+    board.set_square(&pos(3, 4), man(Side::Light));
+    board.set_square(&pos(1, 0), man(Side::Light));
+    let mut game = GameManager::from_board(board, Side::Dark)
+        .with_controllers(Controller::Computer, Controller::Human);
+
+    game.make_move(jump(pos(6, 1), pos(4, 3))).unwrap();
+    // Ending on (2, 1) would let light take the man straight back
+    game.make_ai_move().unwrap();
+
+    assert!(game.pending_capture.is_none());
+    assert_eq!(game.current_turn, Side::Light);
+    assert!(game.board.get_square(&pos(2, 5)).is_some());
+    assert!(game.board.get_square(&pos(3, 4)).is_none());
+    assert!(game.board.get_square(&pos(3, 2)).is_some());
+}
+
+#[test]
+fn search_sees_the_opponents_double_jump() {
+    let mut board = Board::empty();
+    board.set_square(&pos(3, 4), man(Side::Dark));
+    board.set_square(&pos(4, 5), man(Side::Dark));
+    board.set_square(&pos(1, 2), man(Side::Light));
+
+    let mv = decide_move(&board, Side::Dark, 2).unwrap();
+
+    // Stepping to (2, 3) hands light a double jump over both men
+    assert_ne!(mv.to, pos(2, 3));
+}
+
+const CROWNING_PIECES: [(Position, Side); 3] = [
+    (Position { row: 2, col: 1 }, Side::Dark),
+    (Position { row: 1, col: 2 }, Side::Light),
+    (Position { row: 1, col: 4 }, Side::Light),
+];
+
+/// English rules, except that a man crowned mid-capture carries on as a king.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct CrownAndContinue;
+
+impl RuleSet for CrownAndContinue {
+    type State = ();
+
+    fn crowning_ends_capture(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn crowning_ends_the_capture() {
+    let mut game =
+        GameManager::from_board(chain_board(EnglishDraughts, &CROWNING_PIECES), Side::Dark);
+    let moves = moves_per_side(&game.board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].to, pos(0, 3));
+    assert_eq!(moves[0].captured(), &[pos(1, 2)]);
+
+    game.make_move(jump(pos(2, 1), pos(0, 3))).unwrap();
+    assert_eq!(game.current_turn, Side::Light);
+    assert!(game.board.get_square(&pos(0, 3)).unwrap().is_king);
+    assert!(game.board.get_square(&pos(1, 4)).is_some());
+}
+
+#[test]
+fn new_king_keeps_capturing_when_allowed() {
+    let board = chain_board(CrownAndContinue, &CROWNING_PIECES);
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].to, pos(2, 5));
+    assert_eq!(moves[0].captured(), &[pos(1, 2), pos(1, 4)]);
+
+    let mut game = GameManager::from_board(board, Side::Dark);
+    game.make_move(moves[0].clone()).unwrap();
+    assert!(game.board.get_square(&pos(2, 5)).unwrap().is_king);
+}
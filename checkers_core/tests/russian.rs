@@ -0,0 +1,89 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{board_with, pos};
+
+#[test]
+fn man_crowned_mid_capture_continues_as_a_king() {
+    let board = board_with(
+        RussianDraughts,
+        &[
+            (pos(2, 1), Side::Dark, false),
+            (pos(1, 2), Side::Light, false),
+            // Only a king can reach this one from (0, 3)
+            (pos(2, 5), Side::Light, false),
+            (pos(7, 0), Side::Light, false),
+        ],
+    );
+    let mut game = GameManager::from_board(board, Side::Dark);
+    let moves = moves_per_side(&game.board, Side::Dark);
+
+    assert!(!moves.is_empty());
+    assert!(moves.iter().all(|m| m.captured() == [pos(1, 2), pos(2, 5)]));
+
+    game.make_move(moves[0].clone()).unwrap();
+    assert!(game.board.get_square(&moves[0].to).unwrap().is_king);
+}
+
+#[test]
+fn any_capture_may_be_chosen() {
+    let board = board_with(
+        RussianDraughts,
+        &[
+            (pos(7, 0), Side::Dark, false),
+            (pos(6, 1), Side::Light, false),
+            (pos(7, 6), Side::Dark, false),
+            (pos(6, 5), Side::Light, false),
+            (pos(4, 3), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 2);
+    assert!(moves.iter().all(|m| m.is_capture()));
+}
+
+#[test]
+fn men_capture_backwards_and_kings_fly() {
+    let board = board_with(
+        RussianDraughts,
+        &[
+            (pos(4, 3), Side::Dark, false),
+            (pos(5, 4), Side::Light, false),
+            (pos(0, 1), Side::Dark, true),
+            (pos(3, 4), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert!(
+        moves
+            .iter()
+            .any(|m| m.from == pos(4, 3) && m.to == pos(6, 5))
+    );
+    // The king flies over (3, 4) and has to stop on (4, 5), from where it
+    // can take (5, 4) as well
+    assert!(moves.iter().any(|m| m.from == pos(0, 1)
+        && m.to == pos(7, 2)
+        && m.captured() == [pos(3, 4), pos(5, 4)]));
+}
+
+#[test]
+fn king_lands_where_it_can_capture_again() {
+    let board = board_with(
+        RussianDraughts,
+        &[
+            (pos(7, 0), Side::Dark, true),
+            (pos(5, 2), Side::Light, false),
+            (pos(4, 5), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    // Landing on (4, 3), (2, 5), (1, 6) or (0, 7) would end the capture
+    assert_eq!(moves.len(), 2);
+    for mv in &moves {
+        assert_eq!(mv.captured(), [pos(5, 2), pos(4, 5)]);
+        assert!([pos(5, 6), pos(6, 7)].contains(&mv.to));
+    }
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{man, pos, step};
+
+#[test]
+fn dark_does_not_walk_into_a_capture() {
+    let mut board = Board::empty();
+    board.set_square(&pos(4, 1), man(Side::Dark));
+    board.set_square(&pos(2, 3), man(Side::Light));
+
+    let mv = decide_move(&board, Side::Dark, 3).unwrap();
+
+    assert_eq!(mv, step(pos(4, 1), pos(3, 0)));
+}
+
+#[test]
+fn light_does_not_walk_into_a_capture() {
+    let mut board = Board::empty();
+    board.set_square(&pos(3, 6), man(Side::Light));
+    board.set_square(&pos(5, 4), man(Side::Dark));
+
+    let mv = decide_move(&board, Side::Light, 3).unwrap();
+
+    assert_eq!(mv, step(pos(3, 6), pos(4, 7)));
+}
+
+#[test]
+fn both_sides_find_the_winning_capture() {
+    let mut board = Board::empty();
+    board.set_square(&pos(5, 2), man(Side::Dark));
+    board.set_square(&pos(4, 3), man(Side::Light));
+    board.set_square(&pos(7, 6), man(Side::Dark));
+    board.set_square(&pos(0, 1), man(Side::Light));
+
+    let dark = decide_move(&board, Side::Dark, 2).unwrap();
+    let light = decide_move(&board, Side::Light, 2).unwrap();
+
+    assert_eq!(dark.captured(), [pos(4, 3)]);
+    assert_eq!(light.captured(), [pos(5, 2)]);
+}
+
+#[test]
+fn quicker_wins_and_slower_losses_score_higher() {
+    assert!(Score::win_in(1) > Score::win_in(5));
+    assert!(Score::win_in(5) > Score::heuristic(i32::MAX));
+    assert!(Score::heuristic(i32::MIN) > Score::loss_in(5));
+    assert!(Score::loss_in(5) > Score::loss_in(1));
+    assert_eq!(-Score::win_in(3), Score::loss_in(3));
+    assert_eq!(Score::win_in(3).plies_to_win(), Some(3));
+    assert_eq!(Score::loss_in(3).plies_to_loss(), Some(3));
+    assert!(!Score::heuristic(i32::MAX).is_win());
+    assert!(Score::INFINITY > Score::win_in(0));
+}
+
+#[test]
+fn engine_takes_the_quickest_win() {
+    let mut board = Board::empty();
+    board.set_square(
+        &pos(6, 5),
+        Some(PieceData {
+            owner: Side::Dark,
+            is_king: true,
+        }),
+    );
+    board.set_square(&pos(6, 7), man(Side::Light));
+
+    // Standing on (7, 6) leaves the light man with nowhere to go
+    let (mv, score) = analyse(&board, Side::Dark, 4).unwrap();
+
+    assert_eq!(mv, step(pos(6, 5), pos(7, 6)));
+    assert_eq!(score.plies_to_win(), Some(1));
+}
@@ -0,0 +1,147 @@
+use checkers_core::ai::{Bound, Entry};
+mod common;
+
+use checkers_core::prelude::*;
+use common::{king, man, pos, step};
+
+fn entry(key: u64, depth: usize, score: i32) -> Entry {
+    Entry {
+        key,
+        depth,
+        score: Score::heuristic(score),
+        bound: Bound::Exact,
+        best_move: None,
+    }
+}
+
+#[test]
+fn stored_entries_can_be_found_again() {
+    let mut table = TranspositionTable::new(4);
+    table.store(entry(1, 3, 10));
+
+    assert_eq!(table.probe(1).unwrap().score, Score::heuristic(10));
+    assert!(table.probe(2).is_none());
+    // Same slot, different position
+    assert!(table.probe(5).is_none());
+}
+
+#[test]
+fn deeper_entries_are_kept() {
+    let mut table = TranspositionTable::new(4);
+    table.store(entry(1, 4, 10));
+    table.store(entry(5, 2, 20));
+
+    assert_eq!(table.probe(1).unwrap().depth, 4);
+    assert!(table.probe(5).is_none());
+
+    table.store(entry(5, 4, 20));
+    assert!(table.probe(1).is_none());
+    assert_eq!(table.probe(5).unwrap().depth, 4);
+}
+
+#[test]
+fn the_same_position_is_always_replaced() {
+    let mut table = TranspositionTable::new(4);
+    table.store(entry(1, 4, 10));
+    table.store(entry(1, 1, 30));
+
+    let found = table.probe(1).unwrap();
+    assert_eq!(found.depth, 1);
+    assert_eq!(found.score, Score::heuristic(30));
+}
+
+#[test]
+fn clearing_empties_the_table() {
+    let mut table = TranspositionTable::new(8);
+    table.store(entry(1, 1, 0));
+    table.store(entry(2, 1, 0));
+    assert_eq!(table.len(), 2);
+
+    table.clear();
+    assert!(table.is_empty());
+    assert_eq!(table.capacity(), 8);
+}
+
+#[test]
+fn search_fills_the_table() {
+    let board = Board::setup();
+    let mut table = TranspositionTable::new(1024);
+
+    let (first, score) = analyse_with(&board, Side::Dark, 4, &mut table).unwrap();
+    assert!(!table.is_empty());
+
+    assert_eq!(
+        analyse_with(&board, Side::Dark, 4, &mut table),
+        Some((first, score))
+    );
+}
+
+#[test]
+fn wins_keep_their_distance_through_the_table() {
+    // Dark steps aside, the light man has to follow and is jumped: a win in
+    // three plies whichever way the king goes
+    let mut board = Board::empty();
+    board.set_square(&pos(2, 1), king(Side::Dark));
+    board.set_square(&pos(1, 0), man(Side::Light));
+
+    let mut table = TranspositionTable::new(1024);
+    let (mv, score) = analyse_with(&board, Side::Dark, 5, &mut table).unwrap();
+    assert_eq!(score.plies_to_win(), Some(3));
+
+    // The positions below were stored two and more plies from the root;
+    // searched again one ply closer, the loss has to come one ply sooner
+    board.apply_move(&mv).unwrap();
+    let (_, score) = analyse_with(&board, Side::Light, 4, &mut table).unwrap();
+    assert_eq!(score.plies_to_loss(), Some(2));
+    assert_eq!(analyse(&board, Side::Light, 4).unwrap().1, score);
+}
+
+#[test]
+fn frisian_king_counters_are_kept_apart() {
+    // Light to move, with the dark king on the same square after two king
+    // moves, which leave it one more, or after three
+    let frisian = |king_moves: usize| {
+        let mut board = Board::empty_with(FrisianDraughts);
+        let squares = [pos(9, 0), pos(8, 1)];
+        board.set_square(&squares[king_moves % 2], king(Side::Dark));
+        board.set_square(&pos(9, 6), man(Side::Dark));
+        board.set_square(&pos(0, 3), man(Side::Light));
+        board.set_square(&pos(0, 7), man(Side::Light));
+        for ply in (0..king_moves).rev() {
+            let (from, to) = (squares[(ply + 1) % 2], squares[ply % 2]);
+            board.apply_move(&step(from, to)).unwrap();
+        }
+        board
+    };
+    let free = frisian(2);
+    let held = frisian(3);
+    for square in (0..10).flat_map(|row| (0..10).map(move |col| pos(row, col))) {
+        assert_eq!(free.get_square(&square), held.get_square(&square));
+    }
+    assert_eq!(held.state().king_moves(Side::Dark), 3);
+    assert_ne!(free.zobrist(Side::Dark), held.zobrist(Side::Dark));
+
+    let mut table = TranspositionTable::new(1024);
+    analyse_with(&free, Side::Light, 3, &mut table).unwrap();
+
+    assert_eq!(
+        analyse_with(&held, Side::Light, 3, &mut table),
+        analyse(&held, Side::Light, 3)
+    );
+    let (mv, _) = analyse_with(&held, Side::Dark, 3, &mut table).unwrap();
+    assert_eq!(mv.from, pos(9, 6));
+}
+
+#[test]
+fn game_manager_keeps_a_table_until_cleared() {
+    let mut game = GameManager::new()
+        .with_controllers(Controller::Computer, Controller::Computer)
+        .with_table_size(4096);
+    assert_eq!(game.search_table().capacity(), 4096);
+
+    game.make_ai_move().unwrap();
+    assert!(!game.search_table().is_empty());
+
+    game.clear_search_table();
+    assert!(game.search_table().is_empty());
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use checkers_core::prelude::*;
+use common::{board_with, pos};
+
+#[test]
+fn setup_fills_the_second_and_third_rows() {
+    let board = Board::setup_with(TurkishDraughts);
+
+    assert_eq!(board.all_pieces_of_side(Side::Dark).len(), 16);
+    assert_eq!(board.all_pieces_of_side(Side::Light).len(), 16);
+    for col in 0..8 {
+        assert!(board.get_square(&pos(0, col)).is_none());
+        assert!(board.get_square(&pos(7, col)).is_none());
+        assert!(board.get_square(&pos(1, col)).is_some());
+        assert!(board.get_square(&pos(6, col)).is_some());
+    }
+    assert_eq!(moves_per_side(&board, Side::Dark).len(), 8);
+}
+
+#[test]
+fn light_squares_are_playable() {
+    let board = Board::empty_with(TurkishDraughts);
+
+    assert!(board.position(4, 4).is_ok());
+    assert!(Board::empty().position(4, 4).is_err());
+}
+
+#[test]
+fn men_move_forward_and_sideways() {
+    let board = board_with(TurkishDraughts, &[(pos(4, 3), Side::Dark, false)]);
+    let targets: Vec<Position> = moves_per_side(&board, Side::Dark)
+        .into_iter()
+        .map(|m| m.to)
+        .collect();
+
+    assert_eq!(targets.len(), 3);
+    for target in [pos(3, 3), pos(4, 2), pos(4, 4)] {
+        assert!(targets.contains(&target));
+    }
+}
+
+#[test]
+fn men_capture_sideways_but_not_backwards() {
+    let board = board_with(
+        TurkishDraughts,
+        &[
+            (pos(4, 3), Side::Dark, false),
+            (pos(4, 4), Side::Light, false),
+            (pos(5, 3), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].to, pos(4, 5));
+    assert_eq!(moves[0].captured(), &[pos(4, 4)]);
+}
+
+#[test]
+fn kings_fly_along_rows_and_columns() {
+    let board = board_with(
+        TurkishDraughts,
+        &[
+            (pos(7, 0), Side::Dark, true),
+            (pos(3, 0), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    // Three landing squares behind the man on the a-file
+    assert_eq!(moves.len(), 3);
+    assert!(moves.iter().all(|m| m.captured() == [pos(3, 0)]));
+}
+
+#[test]
+fn largest_capture_is_mandatory() {
+    let board = board_with(
+        TurkishDraughts,
+        &[
+            (pos(4, 0), Side::Dark, false),
+            (pos(3, 0), Side::Light, false),
+            (pos(4, 5), Side::Dark, false),
+            (pos(3, 5), Side::Light, false),
+            (pos(2, 4), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].captured(), &[pos(3, 5), pos(2, 4)]);
+}
+
+#[test]
+fn jumped_pieces_come_off_at_once() {
+    let board = board_with(
+        TurkishDraughts,
+        &[
+            (pos(2, 2), Side::Dark, true),
+            (pos(2, 4), Side::Light, false),
+            (pos(4, 5), Side::Light, false),
+            (pos(5, 3), Side::Light, false),
+            (pos(3, 2), Side::Light, false),
+            (pos(2, 6), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    // Round the loop and back along row 2, over the square (2, 4) left
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].to, pos(2, 7));
+    assert_eq!(
+        moves[0].captured(),
+        &[pos(2, 4), pos(4, 5), pos(5, 3), pos(3, 2), pos(2, 6)]
+    );
+}
+
+#[test]
+fn capture_may_not_turn_back() {
+    let board = board_with(
+        TurkishDraughts,
+        &[
+            (pos(2, 3), Side::Dark, true),
+            (pos(2, 4), Side::Light, false),
+            (pos(2, 1), Side::Light, false),
+        ],
+    );
+    let moves = moves_per_side(&board, Side::Dark);
+
+    assert!(!moves.is_empty());
+    assert!(moves.iter().all(|m| m.captured().len() == 1));
+}
+
+#[test]
+fn chain_may_end_where_it_took_a_piece() {
+    let board = board_with(
+        TurkishDraughts,
+        &[
+            (pos(4, 0), Side::Dark, true),
+            (pos(4, 1), Side::Light, false),
+            (pos(2, 3), Side::Light, false),
+            (pos(1, 2), Side::Light, false),
+            (pos(2, 1), Side::Light, false),
+        ],
+    );
+    let mv = moves_per_side(&board, Side::Dark)
+        .into_iter()
+        .find(|m| m.to == pos(4, 1))
+        .unwrap();
+    assert_eq!(mv.captured().len(), 4);
+
+    let mut played = board.clone();
+    let undo = played.apply_move(&mv).unwrap();
+    assert_eq!(played.all_pieces_of_side(Side::Dark).len(), 1);
+    assert!(played.get_square(&pos(4, 1)).unwrap().is_king);
+    assert!(played.all_pieces_of_side(Side::Light).is_empty());
+
+    played.unmake_move(&mv, undo);
+    assert_eq!(played, board);
+}
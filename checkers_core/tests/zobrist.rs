@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+mod common;
+
+use checkers_core::prelude::*;
+use common::{piece, pos, step};
+
+#[test]
+fn move_order_does_not_change_the_hash() {
+    let mut first = Board::setup();
+    first.apply_move(&step(pos(5, 0), pos(4, 1))).unwrap();
+    first.apply_move(&step(pos(5, 2), pos(4, 3))).unwrap();
+
+    let mut second = Board::setup();
+    second.apply_move(&step(pos(5, 2), pos(4, 3))).unwrap();
+    second.apply_move(&step(pos(5, 0), pos(4, 1))).unwrap();
+
+    assert_eq!(first.zobrist(Side::Light), second.zobrist(Side::Light));
+    assert_ne!(
+        first.zobrist(Side::Light),
+        Board::setup().zobrist(Side::Light)
+    );
+}
+
+#[test]
+fn side_to_move_changes_the_hash() {
+    let board = Board::setup();
+
+    assert_ne!(board.zobrist(Side::Dark), board.zobrist(Side::Light));
+}
+
+#[test]
+fn incremental_hash_matches_a_fresh_board() {
+    let mut played = Board::empty();
+    played.set_square(&pos(2, 1), piece(Side::Dark, false));
+    played.set_square(&pos(1, 2), piece(Side::Light, false));
+    played.set_square(&pos(6, 5), piece(Side::Light, false));
+    let capture = moves_per_side(&played, Side::Dark).remove(0);
+    played.apply_move(&capture).unwrap();
+
+    let mut fresh = Board::empty();
+    fresh.set_square(&pos(6, 5), piece(Side::Light, false));
+    fresh.set_square(&pos(0, 3), piece(Side::Dark, true));
+
+    assert_eq!(played, fresh);
+    assert_eq!(played.zobrist(Side::Light), fresh.zobrist(Side::Light));
+}
+
+#[test]
+fn kings_and_men_hash_apart() {
+    let mut man = Board::empty_with(InternationalDraughts);
+    man.set_square(&pos(5, 4), piece(Side::Dark, false));
+    let mut king = Board::empty_with(InternationalDraughts);
+    king.set_square(&pos(5, 4), piece(Side::Dark, true));
+
+    assert_ne!(man.zobrist(Side::Dark), king.zobrist(Side::Dark));
+}
+
+#[test]
+fn boards_work_as_set_keys() {
+    let mut seen = HashSet::new();
+    let start = Board::setup();
+    seen.insert((start.clone(), Side::Dark));
+
+    let mut board = start.clone();
+    let mv = step(pos(5, 0), pos(4, 1));
+    let undo = board.apply_move(&mv).unwrap();
+    assert!(!seen.contains(&(board.clone(), Side::Light)));
+
+    board.unmake_move(&mv, undo);
+    assert!(seen.contains(&(board, Side::Dark)));
+}
+
+/// English rules on a board too large for a fixed table of keys.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Giant;
+
+impl RuleSet for Giant {
+    type State = ();
+
+    fn board_size(&self) -> usize {
+        20
+    }
+
+    fn starting_rows(&self) -> usize {
+        4
+    }
+}
+
+#[test]
+fn boards_of_any_size_are_hashed() {
+    let mut board = Board::setup_with(Giant);
+    let start = board.zobrist(Side::Dark);
+
+    let mv = moves_per_side(&board, Side::Dark).remove(0);
+    let undo = board.apply_move(&mv).unwrap();
+    assert_ne!(board.zobrist(Side::Dark), start);
+
+    board.unmake_move(&mv, undo);
+    assert_eq!(board.zobrist(Side::Dark), start);
+}
+
+#[test]
+fn variant_state_is_part_of_the_hash() {
+    let mut board = Board::empty_with(FrisianDraughts);
+    board.set_square(&pos(9, 0), piece(Side::Dark, true));
+    board.set_square(&pos(0, 1), piece(Side::Light, true));
+    let start = board.zobrist(Side::Dark);
+
+    board.apply_move(&step(pos(9, 0), pos(8, 1))).unwrap();
+    board.apply_move(&step(pos(8, 1), pos(9, 0))).unwrap();
+
+    assert_eq!(board.state().king_moves(Side::Dark), 2);
+    assert_ne!(board.zobrist(Side::Dark), start);
+}